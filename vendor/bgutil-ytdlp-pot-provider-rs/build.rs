@@ -0,0 +1,30 @@
+//! Build script
+//!
+//! Embeds the git commit SHA and build timestamp into the binary as
+//! environment variables consumed by [`utils::version`](src/utils/version.rs).
+
+use std::process::Command;
+
+fn main() {
+    let git_sha = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|sha| sha.trim().to_string());
+
+    if let Some(git_sha) = git_sha {
+        println!("cargo:rustc-env=GIT_SHA={git_sha}");
+    }
+
+    let build_timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={build_timestamp}");
+
+    // Re-run if HEAD moves to a different commit, so dev builds pick up the
+    // new SHA without a full `cargo clean`.
+    println!("cargo:rerun-if-changed=.git/HEAD");
+}
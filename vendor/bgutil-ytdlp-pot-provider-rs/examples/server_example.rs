@@ -18,7 +18,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     settings.token.ttl_hours = 12; // Extended TTL for this example
 
     // Create the Axum app with settings
-    let app = create_app(settings.clone());
+    let app = create_app(settings.clone())?;
 
     // Bind to the configured address
     let addr = format!("{}:{}", settings.server.host, settings.server.port);
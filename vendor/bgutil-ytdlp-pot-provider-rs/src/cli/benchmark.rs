@@ -0,0 +1,224 @@
+//! Benchmark CLI logic
+//!
+//! Contains the core logic for the `benchmark` subcommand, which mints a
+//! batch of tokens against a local `SessionManager` to give operators a
+//! quick throughput/latency measurement for sizing deployments.
+
+use crate::{SessionManager, Settings, config::ConfigLoader, types::PotRequest};
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Arguments for benchmark mode
+#[derive(Debug)]
+pub struct BenchmarkArgs {
+    pub config: Option<String>,
+    pub requests: u32,
+    pub concurrency: u32,
+    pub verbose: bool,
+}
+
+/// Summary statistics for a benchmark run
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkReport {
+    pub requests: u32,
+    pub concurrency: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub total_duration: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub tokens_per_sec: f64,
+}
+
+impl BenchmarkReport {
+    fn print(&self) {
+        println!("requests:      {}", self.requests);
+        println!("concurrency:   {}", self.concurrency);
+        println!("succeeded:     {}", self.succeeded);
+        println!("failed:        {}", self.failed);
+        println!("total time:    {:.3}s", self.total_duration.as_secs_f64());
+        println!("p50 latency:   {:.1}ms", self.p50.as_secs_f64() * 1000.0);
+        println!("p95 latency:   {:.1}ms", self.p95.as_secs_f64() * 1000.0);
+        println!("p99 latency:   {:.1}ms", self.p99.as_secs_f64() * 1000.0);
+        println!("throughput:    {:.2} tokens/sec", self.tokens_per_sec);
+    }
+}
+
+/// Return the `p`-th percentile (0.0-1.0) of already-sorted latencies
+///
+/// Uses nearest-rank: `ceil(p * len)`, clamped to a valid index, so `p100`
+/// of a single-element slice returns that element rather than panicking.
+fn percentile(sorted_latencies: &[Duration], p: f64) -> Duration {
+    if sorted_latencies.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank =
+        ((p * sorted_latencies.len() as f64).ceil() as usize).clamp(1, sorted_latencies.len());
+    sorted_latencies[rank - 1]
+}
+
+/// Mint `requests` tokens against `session_manager`, bypassing the cache so
+/// every request incurs a full mint, bounded to `concurrency` in flight at a
+/// time, and return latency/throughput statistics.
+///
+/// Each request uses a distinct content binding so concurrent mints never
+/// coalesce into a single leader/follower group (see
+/// [`crate::session::SessionManager::generate_pot_token`]), which would
+/// otherwise understate per-request latency.
+pub async fn run_benchmark(
+    session_manager: Arc<SessionManager>,
+    requests: u32,
+    concurrency: u32,
+) -> BenchmarkReport {
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1) as usize));
+    let mut tasks = tokio::task::JoinSet::new();
+    let started_at = Instant::now();
+
+    for i in 0..requests {
+        let session_manager = session_manager.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("benchmark semaphore is never closed");
+            let request = PotRequest::new()
+                .with_content_binding(format!("benchmark_{i}"))
+                .with_bypass_cache(true);
+            let mint_started_at = Instant::now();
+            let result = session_manager.generate_pot_token(&request).await;
+            (result, mint_started_at.elapsed())
+        });
+    }
+
+    let mut latencies = Vec::with_capacity(requests as usize);
+    let mut succeeded = 0;
+    let mut failed = 0;
+    while let Some(result) = tasks.join_next().await {
+        match result {
+            Ok((Ok(_), latency)) => {
+                succeeded += 1;
+                latencies.push(latency);
+            }
+            Ok((Err(e), latency)) => {
+                tracing::warn!("Benchmark request failed: {}", e);
+                failed += 1;
+                latencies.push(latency);
+            }
+            Err(e) => {
+                tracing::error!("Benchmark task panicked: {}", e);
+                failed += 1;
+            }
+        }
+    }
+    let total_duration = started_at.elapsed();
+
+    latencies.sort();
+    let tokens_per_sec = if total_duration.as_secs_f64() > 0.0 {
+        succeeded as f64 / total_duration.as_secs_f64()
+    } else {
+        0.0
+    };
+
+    BenchmarkReport {
+        requests,
+        concurrency,
+        succeeded,
+        failed,
+        total_duration,
+        p50: percentile(&latencies, 0.50),
+        p95: percentile(&latencies, 0.95),
+        p99: percentile(&latencies, 0.99),
+        tokens_per_sec,
+    }
+}
+
+/// Run benchmark mode with the given arguments
+///
+/// Exits the process with a non-zero status if any request fails.
+pub async fn run_benchmark_mode(args: BenchmarkArgs) -> Result<()> {
+    let env_filter = if args.verbose {
+        tracing_subscriber::EnvFilter::new("debug")
+    } else {
+        tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "error".into())
+    };
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .init();
+
+    let config_loader = ConfigLoader::new();
+    let config_path = if let Some(config) = &args.config {
+        Some(std::path::PathBuf::from(config))
+    } else {
+        ConfigLoader::get_config_path()
+    };
+
+    let settings = config_loader
+        .load(config_path.as_deref())
+        .unwrap_or_else(|e| {
+            eprintln!(
+                "Warning: Failed to load configuration: {}. Using defaults.",
+                e
+            );
+            Settings::default()
+        });
+
+    let session_manager = Arc::new(SessionManager::new(settings));
+    let report = run_benchmark(session_manager.clone(), args.requests, args.concurrency).await;
+    session_manager.shutdown().await;
+
+    report.print();
+
+    if report.failed > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_benchmark_tiny_run_completes_with_non_negative_stats() {
+        let session_manager = Arc::new(SessionManager::new(Settings::default()));
+
+        let report = run_benchmark(session_manager.clone(), 2, 2).await;
+        session_manager.shutdown().await;
+
+        assert_eq!(report.requests, 2);
+        assert_eq!(report.concurrency, 2);
+        assert_eq!(report.succeeded + report.failed, 2);
+        assert!(report.total_duration >= Duration::ZERO);
+        assert!(report.p50 >= Duration::ZERO);
+        assert!(report.p95 >= Duration::ZERO);
+        assert!(report.p99 >= Duration::ZERO);
+        assert!(report.tokens_per_sec >= 0.0);
+    }
+
+    #[test]
+    fn test_percentile_of_empty_slice_is_zero() {
+        assert_eq!(percentile(&[], 0.95), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_percentile_of_single_element() {
+        let latencies = vec![Duration::from_millis(42)];
+        assert_eq!(percentile(&latencies, 0.99), Duration::from_millis(42));
+    }
+
+    #[test]
+    fn test_percentile_picks_expected_rank() {
+        let latencies: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+
+        assert_eq!(percentile(&latencies, 0.50), Duration::from_millis(5));
+        assert_eq!(percentile(&latencies, 0.95), Duration::from_millis(10));
+    }
+}
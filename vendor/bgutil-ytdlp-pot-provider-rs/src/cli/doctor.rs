@@ -0,0 +1,210 @@
+//! Doctor diagnostic CLI logic
+//!
+//! Contains the core logic for the `doctor` subcommand, which walks through
+//! the pieces a new setup most commonly gets wrong and prints a pass/fail
+//! line for each, so users don't have to dig through logs to tell whether
+//! their setup works.
+
+use crate::{SessionManager, Settings, config::ConfigLoader, types::PotRequest};
+use anyhow::Result;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Arguments for doctor mode
+#[derive(Debug)]
+pub struct DoctorArgs {
+    pub config: Option<String>,
+    pub verbose: bool,
+}
+
+/// Outcome of a single diagnostic check
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: true,
+            detail: detail.into(),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: false,
+            detail: detail.into(),
+        }
+    }
+
+    fn print(&self) {
+        let status = if self.passed { "PASS" } else { "FAIL" };
+        println!("[{status}] {}: {}", self.name, self.detail);
+    }
+}
+
+/// Run diagnostic checks against an already-loaded configuration
+///
+/// Runs, in sequence: BotGuard initialization (within
+/// `botguard.eager_init_timeout_secs`), minting a test token for a dummy
+/// content binding, and Innertube visitor data generation (using the
+/// configured proxy, if any). Later checks that depend on BotGuard still run
+/// and report failure even if an earlier check failed, so a single broken
+/// piece doesn't hide problems with the rest of the setup.
+pub async fn run_checks(settings: Settings) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+    let session_manager = SessionManager::new(settings.clone());
+
+    let init_timeout = std::time::Duration::from_secs(settings.botguard.eager_init_timeout_secs);
+    let botguard_initialized = match tokio::time::timeout(
+        init_timeout,
+        session_manager.initialize_botguard(),
+    )
+    .await
+    {
+        Ok(Ok(())) => {
+            results.push(CheckResult::pass(
+                "botguard_init",
+                "BotGuard initialized successfully",
+            ));
+            true
+        }
+        Ok(Err(e)) => {
+            results.push(CheckResult::fail(
+                "botguard_init",
+                format!("BotGuard initialization failed: {e}"),
+            ));
+            false
+        }
+        Err(_) => {
+            results.push(CheckResult::fail(
+                "botguard_init",
+                format!(
+                    "BotGuard did not initialize within {}s",
+                    init_timeout.as_secs()
+                ),
+            ));
+            false
+        }
+    };
+
+    if botguard_initialized {
+        let request = PotRequest::new().with_content_binding("doctor_dummy_binding");
+        match session_manager.generate_pot_token(&request).await {
+            Ok(_) => results.push(CheckResult::pass(
+                "test_mint",
+                "Minted a test POT token successfully",
+            )),
+            Err(e) => results.push(CheckResult::fail(
+                "test_mint",
+                format!("Failed to mint a test POT token: {e}"),
+            )),
+        }
+    } else {
+        results.push(CheckResult::fail(
+            "test_mint",
+            "Skipped: BotGuard did not initialize",
+        ));
+    }
+
+    match session_manager.generate_visitor_data().await {
+        Ok(_) => results.push(CheckResult::pass(
+            "innertube_visitor_data",
+            "Innertube visitor data generation succeeded",
+        )),
+        Err(e) => results.push(CheckResult::fail(
+            "innertube_visitor_data",
+            format!("Innertube visitor data generation failed: {e}"),
+        )),
+    }
+
+    session_manager.shutdown().await;
+
+    results
+}
+
+/// Run doctor mode with the given arguments
+///
+/// Exits the process with a non-zero status if any check fails.
+pub async fn run_doctor_mode(args: DoctorArgs) -> Result<()> {
+    let env_filter = if args.verbose {
+        tracing_subscriber::EnvFilter::new("debug")
+    } else {
+        tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "error".into())
+    };
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .init();
+
+    let config_loader = ConfigLoader::new();
+    let config_path = if let Some(config) = &args.config {
+        Some(std::path::PathBuf::from(config))
+    } else {
+        ConfigLoader::get_config_path()
+    };
+
+    let (settings, config_check) = match config_loader.load(config_path.as_deref()) {
+        Ok(settings) => (
+            settings,
+            CheckResult::pass("config", "Configuration loaded and validated successfully"),
+        ),
+        Err(e) => (
+            Settings::default(),
+            CheckResult::fail("config", format!("Failed to load configuration: {e}")),
+        ),
+    };
+
+    let mut results = vec![config_check];
+    results.extend(run_checks(settings).await);
+
+    for result in &results {
+        result.print();
+    }
+
+    if results.iter().any(|r| !r.passed) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_checks_reports_botguard_init_status() {
+        let settings = Settings::default();
+        let results = run_checks(settings).await;
+
+        let botguard_check = results
+            .iter()
+            .find(|r| r.name == "botguard_init")
+            .expect("botguard_init check should always run");
+
+        // BotGuard runs fully in-process (no network dependency for the VM
+        // itself), so in this test environment it's expected to succeed.
+        assert!(botguard_check.passed, "{}", botguard_check.detail);
+    }
+
+    #[tokio::test]
+    async fn test_run_checks_skips_test_mint_when_botguard_fails_to_init() {
+        let mut settings = Settings::default();
+        // A zero-second timeout can never be met, forcing the init check to fail.
+        settings.botguard.eager_init_timeout_secs = 0;
+        let results = run_checks(settings).await;
+
+        let botguard_check = results.iter().find(|r| r.name == "botguard_init").unwrap();
+        assert!(!botguard_check.passed);
+
+        let mint_check = results.iter().find(|r| r.name == "test_mint").unwrap();
+        assert!(!mint_check.passed);
+        assert!(mint_check.detail.contains("Skipped"));
+    }
+}
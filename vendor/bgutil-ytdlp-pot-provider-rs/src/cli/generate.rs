@@ -25,8 +25,23 @@ pub struct GenerateArgs {
     pub bypass_cache: bool,
     pub source_address: Option<String>,
     pub disable_tls_verification: bool,
+    pub timeout: Option<u64>,
     pub version: bool,
     pub verbose: bool,
+    /// Path to a newline-delimited file of content bindings; when set, mints
+    /// one token per line and writes a JSONL stream instead of processing
+    /// `content_binding` as a single request
+    pub batch_file: Option<String>,
+    /// Pretty-print (multi-line, indented) the JSON written to stdout for a
+    /// single request, instead of the default compact single-line form
+    ///
+    /// Ignored in batch mode, since pretty-printing would break the
+    /// one-JSON-object-per-line output format.
+    pub pretty: bool,
+    /// Write the JSON response to this named pipe instead of stdout,
+    /// creating it if it doesn't already exist. Ignored in batch mode,
+    /// which always writes to stdout. Unix only.
+    pub fifo: Option<String>,
 }
 
 /// Run generate mode with the given arguments
@@ -67,6 +82,23 @@ pub async fn run_generate_mode(args: GenerateArgs) -> Result<()> {
         std::process::exit(1);
     }
 
+    if let Some(ref proxy) = args.proxy
+        && let Err(message) = validate_proxy_url(proxy)
+    {
+        eprintln!("Invalid --proxy value: {}", message);
+        std::process::exit(1);
+    }
+
+    let mut args = args;
+    args.content_binding =
+        resolve_content_binding(args.content_binding.as_deref(), &mut std::io::stdin().lock())?;
+
+    let settings = Settings::default();
+    args.content_binding = apply_default_content_binding(
+        args.content_binding,
+        settings.token.default_content_binding.as_deref(),
+    );
+
     debug!(
         "Starting POT generation with parameters: content_binding={:?}, proxy={:?}, bypass_cache={}",
         args.content_binding, args.proxy, args.bypass_cache
@@ -83,17 +115,45 @@ pub async fn run_generate_mode(args: GenerateArgs) -> Result<()> {
     });
 
     // Initialize session manager with cache
-    let settings = Settings::default();
+    let default_timeout_secs = settings.token.pot_generation_timeout;
+    let content_binding_allow_regex = settings.server.compile_content_binding_allow_regex()?;
     let session_manager = SessionManager::new(settings);
     session_manager
         .set_session_data_caches(session_data_caches)
         .await;
 
+    if let Some(ref batch_path) = args.batch_file {
+        return run_batch_generate(
+            &args,
+            &session_manager,
+            &file_cache,
+            batch_path,
+            default_timeout_secs,
+            content_binding_allow_regex.as_ref(),
+        )
+        .await;
+    }
+
     // Build POT request
     let request = build_pot_request(&args)?;
 
-    // Generate POT token
-    match session_manager.generate_pot_token(&request).await {
+    // Generate POT token, bounded by an overall deadline so a hung BotGuard or
+    // Innertube call can't make the process hang forever (e.g. in CI).
+    let timeout_secs = args.timeout.unwrap_or(default_timeout_secs);
+    let generation_result = match check_content_binding_allowed(
+        request.content_binding.as_deref(),
+        content_binding_allow_regex.as_ref(),
+    ) {
+        Err(e) => Err(e),
+        Ok(()) => tokio::time::timeout(
+            std::time::Duration::from_secs(timeout_secs),
+            session_manager.generate_pot_token(&request),
+        )
+        .await
+        .unwrap_or_else(|_| Err(crate::Error::timeout("generate_pot_token", timeout_secs))),
+    };
+
+    match generation_result {
         Ok(response) => {
             // Save updated cache
             if let Err(e) = file_cache
@@ -104,8 +164,8 @@ pub async fn run_generate_mode(args: GenerateArgs) -> Result<()> {
             }
 
             // Output result as JSON
-            let output = serde_json::to_string(&response)?;
-            println!("{}", output);
+            let output = to_json_string(&response, args.pretty)?;
+            let emit_result = emit_output(output, args.fifo.as_deref()).await;
 
             info!(
                 "Successfully generated POT token for content binding: {:?}",
@@ -115,6 +175,18 @@ pub async fn run_generate_mode(args: GenerateArgs) -> Result<()> {
             // Shutdown session manager to properly cleanup V8 isolates
             // This prevents the "v8::OwnedIsolate for snapshot was leaked" warning
             session_manager.shutdown().await;
+
+            match emit_result {
+                Ok(()) => {}
+                Err(e) if is_broken_pipe_error(&e) => {
+                    debug!(
+                        "Output reader disappeared before the response could be written: {}",
+                        e
+                    );
+                    std::process::exit(EXIT_BROKEN_PIPE);
+                }
+                Err(e) => return Err(e),
+            }
         }
         Err(e) => {
             // Shutdown session manager before exiting on error
@@ -123,10 +195,339 @@ pub async fn run_generate_mode(args: GenerateArgs) -> Result<()> {
             eprintln!("Failed while generating POT. Error: {}", e);
 
             // Output empty JSON on error (matching TypeScript behavior)
-            println!("{{}}");
-            std::process::exit(1);
+            if let Err(emit_err) = emit_output("{}".to_string(), args.fifo.as_deref()).await {
+                eprintln!("Additionally failed to write error output: {}", emit_err);
+            }
+            std::process::exit(exit_code_for_error(&e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Run batch mode: mint a token for each newline-delimited content binding
+/// in `batch_path`, writing one JSON object per line to stdout - either a
+/// successful response or an [`crate::types::ErrorResponse`] - so a single
+/// failing binding doesn't stop the rest or abort the process.
+///
+/// Reuses `session_manager` across every line so only the first mint pays
+/// BotGuard's cold-start cost.
+async fn run_batch_generate(
+    args: &GenerateArgs,
+    session_manager: &SessionManager,
+    file_cache: &FileCache,
+    batch_path: &str,
+    default_timeout_secs: u64,
+    content_binding_allow_regex: Option<&regex::Regex>,
+) -> Result<()> {
+    let bindings = tokio::fs::read_to_string(batch_path).await?;
+    let timeout_secs = args.timeout.unwrap_or(default_timeout_secs);
+
+    let mut broken_pipe = false;
+    for line in generate_batch_lines(
+        args,
+        session_manager,
+        bindings.lines(),
+        timeout_secs,
+        content_binding_allow_regex,
+    )
+    .await?
+    {
+        use std::io::Write;
+        if let Err(e) = writeln!(std::io::stdout(), "{}", line) {
+            if e.kind() == std::io::ErrorKind::BrokenPipe {
+                debug!("Output reader disappeared mid-batch, stopping early: {}", e);
+                broken_pipe = true;
+                break;
+            }
+            return Err(e.into());
+        }
+    }
+
+    if let Err(e) = file_cache
+        .save_cache(session_manager.get_session_data_caches(true).await)
+        .await
+    {
+        warn!("Failed to save cache: {}", e);
+    }
+
+    session_manager.shutdown().await;
+
+    if broken_pipe {
+        std::process::exit(EXIT_BROKEN_PIPE);
+    }
+    Ok(())
+}
+
+/// Mint a token for each content binding in `bindings`, returning one JSON
+/// string per line (a success response or an [`crate::types::ErrorResponse`]).
+///
+/// Split out from [`run_batch_generate`] so a test can assert on the produced
+/// lines directly instead of capturing process stdout.
+async fn generate_batch_lines<'a>(
+    args: &GenerateArgs,
+    session_manager: &SessionManager,
+    bindings: impl Iterator<Item = &'a str>,
+    timeout_secs: u64,
+    content_binding_allow_regex: Option<&regex::Regex>,
+) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+
+    for content_binding in bindings {
+        let mut request = build_pot_request(args)?;
+        request.content_binding = Some(content_binding.to_string());
+
+        let result = match check_content_binding_allowed(
+            request.content_binding.as_deref(),
+            content_binding_allow_regex,
+        ) {
+            Err(e) => Err(e),
+            Ok(()) => tokio::time::timeout(
+                std::time::Duration::from_secs(timeout_secs),
+                session_manager.generate_pot_token(&request),
+            )
+            .await
+            .unwrap_or_else(|_| Err(crate::Error::timeout("generate_pot_token", timeout_secs))),
+        };
+
+        let line = match result {
+            Ok(response) => serde_json::to_string(&response)?,
+            Err(e) => {
+                warn!(
+                    "Batch line failed for content binding {:?}: {}",
+                    content_binding, e
+                );
+                serde_json::to_string(&crate::types::ErrorResponse::new(e.to_string()))?
+            }
+        };
+        lines.push(line);
+    }
+
+    Ok(lines)
+}
+
+/// Serialize `value` as compact or pretty-printed JSON depending on `pretty`
+///
+/// Only used for the single-request output path; batch mode always writes
+/// compact JSON regardless of `--pretty`, since a pretty-printed value would
+/// span multiple lines and break the one-JSON-object-per-line format.
+fn to_json_string<T: serde::Serialize>(value: &T, pretty: bool) -> Result<String> {
+    Ok(if pretty {
+        serde_json::to_string_pretty(value)?
+    } else {
+        serde_json::to_string(value)?
+    })
+}
+
+/// How long to wait for a reader to attach to a `--fifo` pipe before giving
+/// up, so a generator with nothing listening on the other end doesn't hang
+/// forever.
+const FIFO_OPEN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Write `output` to stdout, or to the FIFO at `fifo_path` when set,
+/// creating the FIFO if it doesn't already exist.
+async fn emit_output(output: String, fifo_path: Option<&str>) -> Result<()> {
+    match fifo_path {
+        Some(path) => write_to_fifo(path.to_string(), output).await,
+        None => {
+            use std::io::Write;
+            // `println!` panics on a write failure, which would kill the
+            // process (and skip BotGuard cleanup) if the reader on the other
+            // end of a pipe has gone away. `writeln!` surfaces the same
+            // failure as a `Result` instead.
+            writeln!(std::io::stdout(), "{}", output)?;
+            Ok(())
         }
     }
+}
+
+/// Create (if absent) and write `contents` to the named pipe at `path`,
+/// polling with a non-blocking open for a reader so a caller with nothing
+/// listening on the other end doesn't hang forever.
+///
+/// Runs on a blocking task since opening/polling a FIFO is a blocking
+/// syscall with no async equivalent in `tokio::fs`.
+#[cfg(unix)]
+async fn write_to_fifo(path: String, contents: String) -> Result<()> {
+    tokio::task::spawn_blocking(move || write_to_fifo_blocking(&path, &contents)).await?
+}
+
+#[cfg(not(unix))]
+async fn write_to_fifo(_path: String, _contents: String) -> Result<()> {
+    anyhow::bail!("--fifo is only supported on Unix platforms")
+}
+
+/// Blocking half of [`write_to_fifo`]: creates the FIFO if it doesn't exist,
+/// then polls a non-blocking open until a reader attaches or
+/// [`FIFO_OPEN_TIMEOUT`] elapses. Opening a FIFO for writing with
+/// `O_NONBLOCK` set fails with `ENXIO` while no reader has it open, which is
+/// how we distinguish "no reader yet" from a real error.
+#[cfg(unix)]
+fn write_to_fifo_blocking(path: &str, contents: &str) -> Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let path = std::path::Path::new(path);
+    if !path.exists() {
+        nix::unistd::mkfifo(path, nix::sys::stat::Mode::from_bits_truncate(0o644))?;
+    }
+
+    let deadline = std::time::Instant::now() + FIFO_OPEN_TIMEOUT;
+    loop {
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .custom_flags(nix::libc::O_NONBLOCK)
+            .open(path)
+        {
+            Ok(mut file) => {
+                file.write_all(contents.as_bytes())?;
+                file.write_all(b"\n")?;
+                return Ok(());
+            }
+            Err(e) if e.raw_os_error() == Some(nix::libc::ENXIO) => {
+                if std::time::Instant::now() >= deadline {
+                    anyhow::bail!(
+                        "No reader attached to FIFO at {} after {:?}",
+                        path.display(),
+                        FIFO_OPEN_TIMEOUT
+                    );
+                }
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Map an error category to a stable process exit code
+///
+/// Lets wrapping scripts (e.g. in CI) distinguish failure causes without
+/// parsing stderr:
+/// - 2: configuration errors
+/// - 3: network errors
+/// - 4: BotGuard errors
+/// - 5: timeout errors
+/// - 1: anything else
+fn exit_code_for_error(error: &crate::Error) -> i32 {
+    match error.category() {
+        "config" => 2,
+        "network" => 3,
+        "botguard" => 4,
+        "timeout" => 5,
+        _ => 1,
+    }
+}
+
+/// Check `content_binding` against `server.content_binding_allow_regex`, if
+/// both are present. A `None` binding (falling back to visitor-data
+/// generation) or an unset allowlist both pass through unchecked.
+fn check_content_binding_allowed(
+    content_binding: Option<&str>,
+    allow_regex: Option<&regex::Regex>,
+) -> Result<(), crate::Error> {
+    let (Some(binding), Some(allow_regex)) = (content_binding, allow_regex) else {
+        return Ok(());
+    };
+
+    if allow_regex.is_match(binding) {
+        Ok(())
+    } else {
+        Err(crate::Error::validation(
+            "content_binding".to_string(),
+            format!("'{binding}' does not match the configured allowlist"),
+        ))
+    }
+}
+
+/// Exit code used when the reader on the other end of stdout or `--fifo`
+/// disappears before the response could be written, so wrapping scripts can
+/// tell "reader hung up" apart from a real generation failure
+/// ([`exit_code_for_error`]'s codes).
+const EXIT_BROKEN_PIPE: i32 = 6;
+
+/// Whether `error`'s source chain contains a [`std::io::ErrorKind::BrokenPipe`]
+/// I/O error, i.e. the process writing to stdout or `--fifo` had nothing
+/// left reading the other end.
+fn is_broken_pipe_error(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| matches!(cause.downcast_ref::<std::io::Error>(), Some(io_error) if io_error.kind() == std::io::ErrorKind::BrokenPipe))
+}
+
+/// `--content-binding` value that means "read the binding from stdin"
+///
+/// Lets CI pipelines and scripts pipe the binding in rather than passing it
+/// on the command line, where it could end up logged in a process list.
+const STDIN_MARKER: &str = "-";
+
+/// Resolve `--content-binding -` into a value read from `reader`, trimming
+/// the trailing newline; any other value (or `None`) passes through
+/// unchanged.
+///
+/// Takes a generic reader rather than reading `std::io::stdin()` directly so
+/// tests can feed it an in-memory buffer.
+fn resolve_content_binding(
+    content_binding: Option<&str>,
+    reader: &mut impl std::io::BufRead,
+) -> Result<Option<String>> {
+    match content_binding {
+        Some(STDIN_MARKER) => {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            Ok(Some(line.trim_end_matches(['\n', '\r']).to_string()))
+        }
+        other => Ok(other.map(str::to_string)),
+    }
+}
+
+/// Fall back to `token.default_content_binding` when `--content-binding` was
+/// omitted (and not supplied via stdin); otherwise leave `content_binding`
+/// untouched, including the `None` case, which falls back further to visitor
+/// data generation in [`crate::session::SessionManagerGeneric`].
+fn apply_default_content_binding(
+    content_binding: Option<String>,
+    default_content_binding: Option<&str>,
+) -> Option<String> {
+    match content_binding {
+        Some(binding) => Some(binding),
+        None => {
+            if let Some(default_content_binding) = default_content_binding {
+                debug!(
+                    "No --content-binding provided, using token.default_content_binding: {:?}",
+                    default_content_binding
+                );
+            }
+            default_content_binding.map(str::to_string)
+        }
+    }
+}
+
+/// Proxy URL schemes accepted by `--proxy` in generate mode
+const ALLOWED_PROXY_SCHEMES: &[&str] = &["http", "https", "socks5", "socks5h"];
+
+/// Validate a `--proxy` URL's scheme and host/port at argument-parsing time
+///
+/// Catches a typo like `htpp://...` with a clear message up front, instead of
+/// letting it silently produce a confusing failure deeper in proxy setup or
+/// BotGuard.
+fn validate_proxy_url(proxy: &str) -> std::result::Result<(), String> {
+    let url = url::Url::parse(proxy).map_err(|e| format!("'{}' is not a valid URL: {}", proxy, e))?;
+
+    if !ALLOWED_PROXY_SCHEMES.contains(&url.scheme()) {
+        return Err(format!(
+            "scheme '{}' is not supported, must be one of: {}",
+            url.scheme(),
+            ALLOWED_PROXY_SCHEMES.join(", ")
+        ));
+    }
+
+    if url.host_str().is_none() {
+        return Err(format!("'{}' is missing a host", proxy));
+    }
+
+    if url.port_or_known_default().is_none() {
+        return Err(format!("'{}' is missing a port", proxy));
+    }
 
     Ok(())
 }
@@ -176,8 +577,12 @@ mod tests {
             // ... other fields with default values
             visitor_data: None,
             data_sync_id: None,
+            timeout: None,
             version: false,
             verbose: false,
+            batch_file: None,
+            pretty: false,
+            fifo: None,
         };
 
         let request = build_pot_request(&args).unwrap();
@@ -189,4 +594,252 @@ mod tests {
         assert_eq!(request.disable_tls_verification, Some(true));
         assert_eq!(request.disable_innertube, Some(true)); // Should be forced to true
     }
+
+    #[test]
+    fn test_resolve_content_binding_reads_stdin_marker_and_trims_newline() {
+        let mut reader = std::io::Cursor::new(b"piped_video_id\n".to_vec());
+        let resolved = resolve_content_binding(Some("-"), &mut reader).unwrap();
+        assert_eq!(resolved, Some("piped_video_id".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_content_binding_leaves_normal_value_unchanged() {
+        let mut reader = std::io::Cursor::new(Vec::new());
+        let resolved = resolve_content_binding(Some("explicit_video_id"), &mut reader).unwrap();
+        assert_eq!(resolved, Some("explicit_video_id".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_content_binding_passes_through_none() {
+        let mut reader = std::io::Cursor::new(Vec::new());
+        let resolved = resolve_content_binding(None, &mut reader).unwrap();
+        assert_eq!(resolved, None);
+    }
+
+    #[test]
+    fn test_apply_default_content_binding_used_when_none_provided() {
+        let resolved = apply_default_content_binding(None, Some("default_video_id"));
+        assert_eq!(resolved, Some("default_video_id".to_string()));
+    }
+
+    #[test]
+    fn test_apply_default_content_binding_leaves_explicit_binding_unchanged() {
+        let resolved = apply_default_content_binding(
+            Some("explicit_video_id".to_string()),
+            Some("default_video_id"),
+        );
+        assert_eq!(resolved, Some("explicit_video_id".to_string()));
+    }
+
+    #[test]
+    fn test_apply_default_content_binding_falls_through_to_none_when_unset() {
+        let resolved = apply_default_content_binding(None, None);
+        assert_eq!(resolved, None);
+    }
+
+    #[test]
+    fn test_validate_proxy_url_accepts_valid_socks5_url() {
+        assert!(validate_proxy_url("socks5://127.0.0.1:1080").is_ok());
+    }
+
+    #[test]
+    fn test_validate_proxy_url_rejects_malformed_scheme() {
+        let error = validate_proxy_url("htpp://example.com:8080").unwrap_err();
+        assert!(error.contains("not supported"));
+    }
+
+    #[test]
+    fn test_exit_code_for_config_error() {
+        let error = crate::Error::config("network.proxy", "invalid config");
+        assert_eq!(exit_code_for_error(&error), 2);
+    }
+
+    #[test]
+    fn test_exit_code_for_network_error() {
+        let error = crate::Error::network("connection refused");
+        assert_eq!(exit_code_for_error(&error), 3);
+    }
+
+    #[test]
+    fn test_exit_code_for_botguard_error() {
+        let error = crate::Error::botguard("403", "forbidden");
+        assert_eq!(exit_code_for_error(&error), 4);
+    }
+
+    #[test]
+    fn test_exit_code_for_timeout_error() {
+        let error = crate::Error::timeout("generate_pot_token", 30);
+        assert_eq!(exit_code_for_error(&error), 5);
+    }
+
+    #[test]
+    fn test_exit_code_for_other_error_defaults_to_one() {
+        let error = crate::Error::internal("unexpected");
+        assert_eq!(exit_code_for_error(&error), 1);
+    }
+
+    #[test]
+    fn test_to_json_string_compact_by_default() {
+        let value = serde_json::json!({"a": 1, "b": 2});
+        let output = to_json_string(&value, false).unwrap();
+        assert_eq!(output.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_to_json_string_pretty_is_multi_line_and_indented() {
+        let value = serde_json::json!({"a": 1, "b": 2});
+        let output = to_json_string(&value, true).unwrap();
+        assert!(output.lines().count() > 1);
+        assert!(output.lines().any(|line| line.starts_with("  ")));
+    }
+
+    #[tokio::test]
+    async fn test_generate_batch_lines_reports_error_for_empty_binding_without_aborting() {
+        let args = GenerateArgs {
+            content_binding: None,
+            visitor_data: None,
+            data_sync_id: None,
+            proxy: None,
+            bypass_cache: false,
+            source_address: None,
+            disable_tls_verification: false,
+            timeout: None,
+            version: false,
+            verbose: false,
+            batch_file: None,
+            pretty: false,
+            fifo: None,
+        };
+        let session_manager = SessionManager::new(Settings::default());
+        let bindings = ["good_video_id", ""];
+
+        let lines = generate_batch_lines(&args, &session_manager, bindings.into_iter(), 30, None)
+            .await
+            .unwrap();
+
+        assert_eq!(lines.len(), 2);
+
+        let success: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(success["content_binding"], "good_video_id");
+
+        let failure: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+        assert!(
+            failure.get("error").is_some(),
+            "empty binding should produce an error object, got: {}",
+            lines[1]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_timeout_fires_for_slow_provider() {
+        use crate::session::SessionManagerGeneric;
+        use crate::session::innertube::InnertubeProvider;
+        use async_trait::async_trait;
+
+        struct SlowProvider;
+
+        #[async_trait]
+        impl InnertubeProvider for SlowProvider {
+            async fn generate_visitor_data(
+                &self,
+                _user_agent: Option<&str>,
+                _options: &crate::session::network::RequestOptions,
+            ) -> crate::Result<String> {
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                Ok("visitor_data_1234567890".to_string())
+            }
+
+            async fn get_challenge(
+                &self,
+                _context: &crate::types::InnertubeContext,
+                _options: &crate::session::network::RequestOptions,
+            ) -> crate::Result<crate::types::ChallengeData> {
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                Err(crate::Error::internal("not reached"))
+            }
+        }
+
+        let manager = SessionManagerGeneric::new_with_provider(Settings::default(), SlowProvider);
+
+        // Mirrors the `.unwrap_or_else` timeout wrapping in `run_generate_mode`
+        let result = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            manager.generate_visitor_data(),
+        )
+        .await
+        .unwrap_or_else(|_| Err(crate::Error::timeout("generate_visitor_data", 0)));
+
+        assert!(matches!(result, Err(crate::Error::Timeout { .. })));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_to_fifo_delivers_contents_to_a_reader() {
+        let dir = tempfile::tempdir().unwrap();
+        let fifo_path = dir.path().join("pot.fifo");
+        let fifo_path_str = fifo_path.to_str().unwrap().to_string();
+
+        let reader_path = fifo_path.clone();
+        let reader = tokio::task::spawn_blocking(move || {
+            // Wait for the writer to create the FIFO before opening it for
+            // reading - std::fs::read_to_string's blocking open then
+            // rendezvouses with the writer's non-blocking one.
+            while !reader_path.exists() {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            std::fs::read_to_string(&reader_path).unwrap()
+        });
+
+        write_to_fifo(fifo_path_str, "hello from fifo".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(reader.await.unwrap(), "hello from fifo\n");
+    }
+
+    #[test]
+    fn test_is_broken_pipe_error_matches_broken_pipe_io_error() {
+        let error: anyhow::Error = std::io::Error::from(std::io::ErrorKind::BrokenPipe).into();
+        assert!(is_broken_pipe_error(&error));
+    }
+
+    #[test]
+    fn test_is_broken_pipe_error_rejects_other_io_errors() {
+        let error: anyhow::Error = std::io::Error::from(std::io::ErrorKind::NotFound).into();
+        assert!(!is_broken_pipe_error(&error));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_to_fifo_surfaces_broken_pipe_without_panicking() {
+        let dir = tempfile::tempdir().unwrap();
+        let fifo_path = dir.path().join("pot.fifo");
+        let fifo_path_str = fifo_path.to_str().unwrap().to_string();
+
+        // Open the FIFO for reading exactly once, rendezvousing with the
+        // writer's non-blocking open, then drop it immediately - by the time
+        // the writer gets around to its `write_all`, there's a good chance
+        // no reader is left, which is what we're exercising here.
+        let reader_path = fifo_path.clone();
+        let reader = std::thread::spawn(move || {
+            while !reader_path.exists() {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+            drop(std::fs::File::open(&reader_path));
+        });
+
+        let result = write_to_fifo(fifo_path_str, "hello from fifo".to_string()).await;
+        reader.join().unwrap();
+
+        // Either outcome is a legitimate result of the open/write race, but
+        // neither should panic, and an error must be recognizable as a
+        // broken pipe rather than bubbling up as an opaque failure.
+        if let Err(e) = result {
+            assert!(
+                is_broken_pipe_error(&e),
+                "expected a broken pipe error, got: {:?}",
+                e
+            );
+        }
+    }
 }
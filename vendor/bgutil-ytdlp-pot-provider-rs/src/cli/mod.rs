@@ -2,5 +2,7 @@
 //!
 //! This module contains the CLI logic for both server and generate modes.
 
+pub mod benchmark;
+pub mod doctor;
 pub mod generate;
 pub mod server;
@@ -64,7 +64,7 @@ impl ConfigLoader {
         if let Some(path) = config_file {
             if path.exists() {
                 info!("Loading configuration from file: {:?}", path);
-                settings = Settings::from_file(path)?;
+                settings = Settings::from_file(path)?.expand_env_vars()?;
             } else {
                 warn!("Configuration file not found: {:?}, using defaults", path);
             }
@@ -90,12 +90,81 @@ impl ConfigLoader {
         Ok(settings)
     }
 
+    /// Load configuration, layering multiple files in order
+    ///
+    /// Each file is parsed on its own, then deep-merged onto the result so
+    /// far: a field a later file doesn't mention keeps the value an earlier
+    /// file gave it, while a field it does mention overrides it, section by
+    /// section. Lets a deployment keep a base config plus a small
+    /// environment-specific overlay instead of duplicating the whole file.
+    /// Environment variable overrides are applied last, after every layer,
+    /// matching [`Self::load`]'s precedence. A missing path is skipped with
+    /// a warning rather than treated as an error, so an optional overlay
+    /// that doesn't exist in a given environment doesn't break startup.
+    pub fn load_layered(&self, config_files: &[std::path::PathBuf]) -> Result<Settings> {
+        let mut merged = toml::Value::Table(toml::Table::new());
+
+        for path in config_files {
+            if !path.exists() {
+                warn!("Configuration file not found: {:?}, skipping", path);
+                continue;
+            }
+
+            info!("Layering configuration from file: {:?}", path);
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                crate::Error::config("file", &format!("Failed to read config file: {}", e))
+            })?;
+            let layer: toml::Value = toml::from_str(&content).map_err(|e| {
+                crate::Error::config("file", &format!("Failed to parse config file: {}", e))
+            })?;
+
+            merge_toml_values(&mut merged, layer);
+        }
+
+        let settings: Settings = merged.try_into().map_err(|e| {
+            crate::Error::config("file", &format!("Failed to parse merged configuration: {}", e))
+        })?;
+
+        let mut settings = settings.expand_env_vars()?;
+
+        debug!("Applying environment variable overrides");
+        settings = settings.merge_with_env()?;
+
+        settings.validate()?;
+
+        info!("Layered configuration loaded successfully");
+        debug!("Final configuration: {:?}", settings);
+
+        Ok(settings)
+    }
+
     /// Get default configuration
     pub fn defaults(&self) -> &Settings {
         &self.defaults
     }
 }
 
+/// Deep-merge `overlay` onto `base` in place
+///
+/// Tables are merged key by key, recursing into nested tables; any other
+/// value (including arrays, which aren't concatenated) is simply replaced
+/// by the overlay's value.
+fn merge_toml_values(base: &mut toml::Value, overlay: toml::Value) {
+    match (base, overlay) {
+        (toml::Value::Table(base_table), toml::Value::Table(overlay_table)) => {
+            for (key, overlay_value) in overlay_table {
+                match base_table.get_mut(&key) {
+                    Some(base_value) => merge_toml_values(base_value, overlay_value),
+                    None => {
+                        base_table.insert(key, overlay_value);
+                    }
+                }
+            }
+        }
+        (base_slot, overlay_value) => *base_slot = overlay_value,
+    }
+}
+
 impl Default for ConfigLoader {
     fn default() -> Self {
         Self::new()
@@ -187,6 +256,145 @@ ttl_hours = 12
         }
     }
 
+    #[test]
+    fn test_env_var_expansion_resolves_variable() {
+        let _lock = ENV_TEST_MUTEX.lock().unwrap();
+
+        unsafe {
+            std::env::set_var("BGUTIL_TEST_EXPAND_VAR", "https://proxy.internal:8080");
+        }
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+[network]
+https_proxy = "${{BGUTIL_TEST_EXPAND_VAR}}"
+        "#
+        )
+        .unwrap();
+
+        let loader = ConfigLoader::new();
+        let settings = loader.load(Some(temp_file.path())).unwrap();
+
+        assert_eq!(
+            settings.network.https_proxy,
+            Some("https://proxy.internal:8080".to_string())
+        );
+
+        unsafe {
+            std::env::remove_var("BGUTIL_TEST_EXPAND_VAR");
+        }
+    }
+
+    #[test]
+    fn test_env_var_expansion_errors_on_unset_variable() {
+        let _lock = ENV_TEST_MUTEX.lock().unwrap();
+
+        unsafe {
+            std::env::remove_var("BGUTIL_TEST_UNSET_VAR");
+        }
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+[network]
+https_proxy = "${{BGUTIL_TEST_UNSET_VAR}}"
+        "#
+        )
+        .unwrap();
+
+        let loader = ConfigLoader::new();
+        let result = loader.load(Some(temp_file.path()));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_env_var_expansion_leaves_literal_dollar_alone() {
+        let _lock = ENV_TEST_MUTEX.lock().unwrap();
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(
+            temp_file,
+            r#"
+[server]
+host = "$not_a_reference"
+        "#
+        )
+        .unwrap();
+
+        let loader = ConfigLoader::new();
+        let settings = loader.load(Some(temp_file.path())).unwrap();
+
+        assert_eq!(settings.server.host, "$not_a_reference");
+    }
+
+    #[test]
+    fn test_load_layered_overlay_overrides_base_and_keeps_unspecified_fields() {
+        let mut base_file = NamedTempFile::new().unwrap();
+        writeln!(
+            base_file,
+            r#"
+[server]
+host = "base-host"
+port = 8080
+
+[token]
+ttl_hours = 12
+        "#
+        )
+        .unwrap();
+
+        let mut overlay_file = NamedTempFile::new().unwrap();
+        writeln!(
+            overlay_file,
+            r#"
+[server]
+port = 9090
+        "#
+        )
+        .unwrap();
+
+        let loader = ConfigLoader::new();
+        let settings = loader
+            .load_layered(&[
+                base_file.path().to_path_buf(),
+                overlay_file.path().to_path_buf(),
+            ])
+            .unwrap();
+
+        // Overridden by the overlay
+        assert_eq!(settings.server.port, 9090);
+        // Left unspecified by the overlay, so the base file's value survives
+        assert_eq!(settings.server.host, "base-host");
+        assert_eq!(settings.token.ttl_hours, 12);
+    }
+
+    #[test]
+    fn test_load_layered_skips_missing_files() {
+        let mut base_file = NamedTempFile::new().unwrap();
+        writeln!(
+            base_file,
+            r#"
+[server]
+host = "base-host"
+        "#
+        )
+        .unwrap();
+
+        let loader = ConfigLoader::new();
+        let settings = loader
+            .load_layered(&[
+                base_file.path().to_path_buf(),
+                std::path::PathBuf::from("/nonexistent/overlay.toml"),
+            ])
+            .unwrap();
+
+        assert_eq!(settings.server.host, "base-host");
+    }
+
     #[test]
     fn test_proxy_priority() {
         let mut settings = Settings::default();
@@ -7,4 +7,4 @@ pub mod loader;
 pub mod settings;
 
 pub use loader::ConfigLoader;
-pub use settings::Settings;
+pub use settings::{DeprecatedFieldPolicy, InnertubeClientName, ServerSettings, Settings};
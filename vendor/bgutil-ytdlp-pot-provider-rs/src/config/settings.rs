@@ -33,6 +33,50 @@ fn default_log_level() -> String {
     "info".to_string()
 }
 
+/// Replace `${VAR}` occurrences in `value` with `VAR`'s environment value
+///
+/// `field` is only used to name the offending field in error messages. A
+/// bare `$` not followed by `{` is left alone, so literal dollar signs in
+/// e.g. proxy credentials don't need escaping.
+fn expand_env_var_refs(field: &str, value: &str) -> crate::Result<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(dollar_pos) = rest.find('$') {
+        result.push_str(&rest[..dollar_pos]);
+        let after_dollar = &rest[dollar_pos + 1..];
+
+        if !after_dollar.starts_with('{') {
+            result.push('$');
+            rest = after_dollar;
+            continue;
+        }
+
+        let after_brace = &after_dollar[1..];
+        let Some(close) = after_brace.find('}') else {
+            return Err(crate::Error::config(
+                field,
+                &format!("Unterminated variable reference in '{value}'"),
+            ));
+        };
+
+        let var_name = &after_brace[..close];
+        let var_value = std::env::var(var_name).map_err(|_| {
+            crate::Error::config(
+                field,
+                &format!(
+                    "Environment variable '{var_name}' referenced by '${{{var_name}}}' is not set"
+                ),
+            )
+        })?;
+        result.push_str(&var_value);
+        rest = &after_brace[close + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
 fn default_log_format() -> String {
     "text".to_string()
 }
@@ -65,6 +109,10 @@ fn default_vm_timeout() -> u64 {
     30
 }
 
+fn default_eager_init_timeout_secs() -> u64 {
+    30
+}
+
 fn default_memory_cache_size() -> usize {
     100
 }
@@ -77,10 +125,22 @@ fn default_pot_generation_timeout() -> u64 {
     30 // 30 seconds
 }
 
+fn default_max_minter_entries() -> usize {
+    usize::MAX // effectively unlimited unless explicitly bounded
+}
+
+fn default_mint_refresh_threshold_secs() -> u32 {
+    300 // 5 minutes, matches the previous hardcoded behavior
+}
+
 fn default_ttl_hours() -> u64 {
     6
 }
 
+fn default_ttl_jitter_secs() -> u64 {
+    0 // no jitter, preserves previous fixed-TTL behavior
+}
+
 // Duration serialization module
 mod duration_secs {
     use serde::{Deserialize, Deserializer, Serializer};
@@ -133,6 +193,18 @@ fn default_port() -> u16 {
     4416
 }
 
+fn default_shutdown_grace_secs() -> u64 {
+    30
+}
+
+fn default_bind_retries() -> u32 {
+    5
+}
+
+fn default_bind_retry_interval_ms() -> u64 {
+    500
+}
+
 /// HTTP server configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerSettings {
@@ -151,6 +223,143 @@ pub struct ServerSettings {
     /// Maximum request body size
     #[serde(default = "default_max_body_size")]
     pub max_body_size: usize,
+    /// Path to a Unix domain socket to listen on instead of TCP
+    ///
+    /// When set, `host`/`port` are ignored and the server is reachable only
+    /// on the local filesystem, avoiding TCP entirely.
+    #[serde(default)]
+    pub unix_socket: Option<std::path::PathBuf>,
+    /// Origins allowed to make cross-origin requests
+    ///
+    /// Empty (the default) combined with `enable_cors = true` reflects any
+    /// origin, matching the previous behavior. A non-empty list restricts
+    /// the `Access-Control-Allow-Origin` header to exactly these origins.
+    #[serde(default)]
+    pub cors_allowed_origins: Vec<String>,
+    /// Enable gzip/deflate compression of responses, honoring the client's
+    /// `Accept-Encoding` header
+    ///
+    /// Defaults to `false` so existing clients that don't expect a
+    /// `Content-Encoding` header see no behavior change.
+    #[serde(default)]
+    pub enable_compression: bool,
+    /// Bearer token required to call admin endpoints (`POST /reinitialize`,
+    /// `GET /config`, `GET /cache/entries` and
+    /// `DELETE /cache/entries/{binding}`). When unset, those endpoints are
+    /// unprotected.
+    #[serde(default)]
+    pub auth_token: Option<String>,
+    /// Reject `/get_pot` requests containing fields `PotRequest` doesn't
+    /// recognize with a 422, instead of the default of logging them at
+    /// debug level and ignoring them
+    ///
+    /// The lenient default helps forward-compatibility as yt-dlp evolves
+    /// its request body ahead of this server adding matching fields; strict
+    /// mode is for deployments that want to catch client/server drift early.
+    #[serde(default)]
+    pub reject_unknown_fields: bool,
+    /// On shutdown, how long to keep waiting for in-flight requests to
+    /// finish (after new connections have already stopped being accepted)
+    /// before giving up and shutting the session manager down anyway
+    #[serde(default = "default_shutdown_grace_secs")]
+    pub shutdown_grace_secs: u64,
+    /// Accept a PROXY protocol v1 header at the start of each TCP
+    /// connection (as sent by HAProxy, AWS NLB, etc.) and use the client
+    /// address it carries instead of the raw TCP peer address for logging
+    /// and `ConnectInfo`. Ignored when `unix_socket` is set. Only enable
+    /// this when the server is reachable exclusively through a proxy that
+    /// is configured to send the header - a client connecting directly
+    /// could otherwise spoof its address.
+    #[serde(default)]
+    pub accept_proxy_protocol: bool,
+    /// CIDR blocks (e.g. `"10.0.0.0/8"`, `"2001:db8::/32"`) of reverse
+    /// proxies trusted to report the real client address via
+    /// `X-Forwarded-For`. Only used when the request's peer address falls
+    /// within one of these blocks; the header is otherwise ignored so an
+    /// untrusted peer can't spoof its own address. Empty by default, which
+    /// always uses the raw peer address.
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
+    /// How `/get_pot` requests containing deprecated fields (currently just
+    /// `visitor_data`) are handled
+    ///
+    /// Defaults to `warn` rather than the old hard-`reject` behavior so
+    /// older yt-dlp clients that still send `visitor_data` harmlessly
+    /// alongside `content_binding` keep working.
+    #[serde(default)]
+    pub deprecated_field_policy: DeprecatedFieldPolicy,
+    /// Number of additional attempts to bind the TCP listener if the port
+    /// is transiently still held by a previous process (e.g. during a
+    /// container restart), before giving up and returning an error
+    #[serde(default = "default_bind_retries")]
+    pub bind_retries: u32,
+    /// Delay, in milliseconds, between bind attempts
+    #[serde(default = "default_bind_retry_interval_ms")]
+    pub bind_retry_interval_ms: u64,
+    /// Pretty-print (multi-line, indented) the JSON body of error responses,
+    /// instead of the default compact single-line form
+    ///
+    /// Meant for interactive debugging; left off by default since most
+    /// consumers are automated clients that don't benefit from the extra
+    /// whitespace.
+    #[serde(default)]
+    pub pretty_errors: bool,
+    /// Negotiate HTTP/2 (h2c, since this server has no TLS support of its
+    /// own) on the plain TCP listener, in addition to HTTP/1.1
+    ///
+    /// Lets clients that mint many tokens in quick succession multiplex
+    /// those requests over a single connection instead of opening one per
+    /// request. Existing HTTP/1.1 clients are unaffected either way -
+    /// protocol is negotiated per connection. Only applies to the plain TCP
+    /// listener; `unix_socket` and `accept_proxy_protocol` connections are
+    /// always served as HTTP/1.1 regardless of this setting.
+    #[serde(default)]
+    pub enable_http2: bool,
+    /// Key used to sign `/get_pot` response bodies with `X-POT-Signature`
+    /// (an HMAC-SHA256 over the canonical JSON body), letting a client that
+    /// goes through caches or other untrusted intermediaries verify the
+    /// response wasn't tampered with in transit. Unset by default, which
+    /// skips signing and omits the header entirely. See
+    /// [`crate::utils::signature::sign_response_body`] /
+    /// [`crate::utils::signature::verify_response_signature`].
+    #[serde(default)]
+    pub response_signing_key: Option<String>,
+    /// Close a TCP connection that's gone idle (no bytes read or written)
+    /// for this many seconds, so a yt-dlp client that opens a connection and
+    /// never finishes the request doesn't tie up resources indefinitely.
+    /// `0` disables idle timeouts entirely, preserving previous behavior.
+    /// Only applies to the plain TCP listener; `unix_socket` and
+    /// `accept_proxy_protocol` connections are never idle-timed-out.
+    #[serde(default)]
+    pub http_idle_timeout_secs: u64,
+    /// Allow HTTP keep-alive, letting a client reuse a connection for more
+    /// than one request. Defaults to `true`, matching hyper's own default.
+    /// Only applies to the plain TCP listener, for the same reason as
+    /// `http_idle_timeout_secs`.
+    #[serde(default = "default_true")]
+    pub http_keepalive: bool,
+    /// Regex a `content_binding` must fully match to be minted, letting a
+    /// public-facing server restrict itself to e.g. 11-character video IDs
+    /// and reduce abuse from arbitrary bindings. Checked in
+    /// [`Settings::validate`] at startup and enforced by
+    /// `crate::server::handlers::generate_pot`. Unset by default, which
+    /// allows any binding, preserving previous behavior.
+    #[serde(default)]
+    pub content_binding_allow_regex: Option<String>,
+}
+
+/// How a `/get_pot` request containing a deprecated field is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeprecatedFieldPolicy {
+    /// Reject the request with a 400, as before deprecated fields had a policy
+    Reject,
+    /// Log the deprecated field, add a `Warning` response header, and
+    /// process the request normally using `content_binding`
+    #[default]
+    Warn,
+    /// Silently process the request normally using `content_binding`
+    Ignore,
 }
 
 /// Token generation and caching configuration
@@ -159,6 +368,12 @@ pub struct TokenSettings {
     /// Token TTL in hours (corresponds to TypeScript TOKEN_TTL env var)
     #[serde(default = "default_ttl_hours")]
     pub ttl_hours: u64,
+    /// Maximum random jitter, in seconds, applied to each minted token's
+    /// `expires_at` to avoid many tokens minted in the same window expiring
+    /// in lockstep and causing a BotGuard mint stampede. Defaults to 0 (no
+    /// jitter), preserving the previous fixed-TTL behavior.
+    #[serde(default = "default_ttl_jitter_secs")]
+    pub ttl_jitter_secs: u64,
     /// Enable token caching
     #[serde(default = "default_true")]
     pub enable_cache: bool,
@@ -174,6 +389,49 @@ pub struct TokenSettings {
     /// POT token generation timeout in seconds
     #[serde(default = "default_pot_generation_timeout")]
     pub pot_generation_timeout: u64,
+    /// Seconds before expiry at which a cached minter is considered due for
+    /// refresh, capped at `lifetime_secs / 2` (default matches the previous
+    /// hardcoded 300s)
+    #[serde(default = "default_mint_refresh_threshold_secs")]
+    pub mint_refresh_threshold_secs: u32,
+    /// Maximum entries kept in the token minter cache before the
+    /// least-recently-used entry is evicted. Defaults to effectively
+    /// unlimited so existing deployments see no behavior change.
+    #[serde(default = "default_max_minter_entries")]
+    pub max_minter_entries: usize,
+    /// Minimum remaining lifetime, in seconds, a cached POT token must have
+    /// to still be served from cache; below this a fresh token is minted
+    /// instead, so yt-dlp doesn't start a long download with a token that's
+    /// about to expire mid-stream. Defaults to 0 (serve any non-expired
+    /// cached token), preserving the previous behavior.
+    #[serde(default)]
+    pub min_remaining_secs: u64,
+    /// Seconds before expiry at which a cached token is still served
+    /// immediately but also triggers a background re-mint, so a caller never
+    /// pays mint latency when a cache entry happens to be near expiry.
+    /// Defaults to 0 (disabled), preserving the previous behavior of minting
+    /// synchronously once a token falls below `min_remaining_secs`.
+    #[serde(default)]
+    pub stale_while_revalidate_secs: u64,
+    /// Content binding used by generate mode when `--content-binding` is
+    /// omitted and the binding wasn't piped via stdin. Unset preserves the
+    /// previous behavior of generating fresh visitor data instead.
+    #[serde(default)]
+    pub default_content_binding: Option<String>,
+    /// Upper bound, in seconds from now, on how long a minted token or
+    /// minter is cached, regardless of the lifetime BotGuard reports. Lets
+    /// operators force more frequent re-validation than BotGuard's own
+    /// lifetime would otherwise allow. Unset preserves the previous
+    /// behavior of trusting BotGuard's reported lifetime entirely.
+    #[serde(default)]
+    pub max_lifetime_secs: Option<u64>,
+    /// When a fresh mint fails and an expired cached token exists for the
+    /// same key, serve that stale token instead of failing the request
+    /// outright, marking the response `isStale: true`. Defaults to false
+    /// (a mint failure always fails the request), since a stale token may
+    /// no longer validate with YouTube.
+    #[serde(default)]
+    pub serve_stale_on_error: bool,
 }
 
 /// Logging configuration
@@ -191,6 +449,19 @@ pub struct LoggingSettings {
     /// Enable request/response logging
     #[serde(default = "default_true")]
     pub log_requests: bool,
+    /// Path to append a JSON Lines audit record to for every successful POT
+    /// mint - timestamp, a SHA-256 hash of the content binding (never the
+    /// plaintext binding or the token itself), proxy host, token type, and
+    /// expiry. Unset by default, which skips audit logging entirely. See
+    /// [`crate::utils::audit::AuditLogger`].
+    #[serde(default)]
+    pub audit_file: Option<std::path::PathBuf>,
+    /// Replace content bindings (which may be private video IDs or visitor
+    /// data) in `generate_pot_token`'s log output with a short stable hash -
+    /// the first 8 hex characters of its SHA-256 digest - instead of logging
+    /// them in plaintext
+    #[serde(default)]
+    pub hash_content_bindings: bool,
 }
 
 /// Network and proxy configuration
@@ -220,6 +491,53 @@ pub struct NetworkSettings {
     /// User agent string
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// Pool of proxy URLs to rotate through when a request doesn't specify its own proxy
+    #[serde(default)]
+    pub proxy_pool: Vec<String>,
+    /// Pool of User-Agent strings to rotate through per mint, for both the
+    /// Innertube HTTP client and BotGuard. Falls back to the single
+    /// `user_agent` above when empty.
+    #[serde(default)]
+    pub user_agent_pool: Vec<String>,
+    /// Override for the Innertube API base URL, for self-hosting or routing
+    /// through a caching mirror. Defaults to the standard
+    /// `https://www.youtube.com/youtubei/v1` when unset.
+    #[serde(default)]
+    pub innertube_base_url: Option<String>,
+    /// Innertube `clientName` sent with visitor-data generation requests
+    ///
+    /// Different YouTube flows validate against different clients - e.g.
+    /// embedded players often require `TVHTML5` rather than the default
+    /// `WEB`.
+    #[serde(default)]
+    pub innertube_client_name: InnertubeClientName,
+}
+
+/// Known Innertube `clientName` values accepted by
+/// [`NetworkSettings::innertube_client_name`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InnertubeClientName {
+    /// The standard web client; what every other client name defaults to
+    #[default]
+    #[serde(rename = "WEB")]
+    Web,
+    /// Mobile web client
+    #[serde(rename = "MWEB")]
+    Mweb,
+    /// TV/embedded-player client, required by some age/embed-restricted flows
+    #[serde(rename = "TVHTML5")]
+    Tvhtml5,
+}
+
+impl InnertubeClientName {
+    /// The literal `clientName` string sent in the Innertube request body
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Web => "WEB",
+            Self::Mweb => "MWEB",
+            Self::Tvhtml5 => "TVHTML5",
+        }
+    }
 }
 
 /// BotGuard specific configuration
@@ -241,14 +559,34 @@ pub struct BotGuardSettings {
     #[serde(default)]
     pub challenge_endpoint: Option<String>,
     /// BotGuard snapshot file path for caching
+    ///
+    /// May contain `{pid}` and/or `{port}` placeholders, expanded at startup
+    /// using the process ID and `server.port` respectively, so multiple
+    /// instances sharing a host (e.g. `snapshot_{port}.bin`) don't share one
+    /// snapshot file.
     #[serde(default)]
     pub snapshot_path: Option<std::path::PathBuf>,
+    /// Read-only snapshot path to seed `snapshot_path` from when the latter
+    /// doesn't exist yet, for immutable-container deployments that bake a
+    /// pre-generated snapshot into the image on a read-only path while
+    /// `snapshot_path` points somewhere writable (e.g. a mounted volume).
+    /// Only consulted once, the first time a given `snapshot_path` is about
+    /// to be used and is missing; falls back to minting a fresh in-memory
+    /// one as usual if this is also unset or missing. Never written to.
+    #[serde(default)]
+    pub snapshot_read_path: Option<std::path::PathBuf>,
     /// Custom User Agent for BotGuard
     #[serde(default)]
     pub user_agent: Option<String>,
     /// Disable snapshot functionality
     #[serde(default)]
     pub disable_snapshot: bool,
+    /// Initialize BotGuard eagerly at server startup instead of on the first `/get_pot` request
+    #[serde(default = "default_true")]
+    pub eager_init: bool,
+    /// Timeout in seconds for eager BotGuard initialization before startup fails fast
+    #[serde(default = "default_eager_init_timeout_secs")]
+    pub eager_init_timeout_secs: u64,
 }
 
 /// Cache configuration
@@ -276,19 +614,72 @@ impl Default for ServerSettings {
             timeout: default_timeout(),
             enable_cors: default_true(),
             max_body_size: default_max_body_size(),
+            unix_socket: None,
+            cors_allowed_origins: Vec::new(),
+            enable_compression: false,
+            auth_token: None,
+            reject_unknown_fields: false,
+            shutdown_grace_secs: default_shutdown_grace_secs(),
+            accept_proxy_protocol: false,
+            trusted_proxies: Vec::new(),
+            deprecated_field_policy: DeprecatedFieldPolicy::default(),
+            bind_retries: default_bind_retries(),
+            bind_retry_interval_ms: default_bind_retry_interval_ms(),
+            pretty_errors: false,
+            enable_http2: false,
+            response_signing_key: None,
+            http_idle_timeout_secs: 0,
+            http_keepalive: default_true(),
+            content_binding_allow_regex: None,
         }
     }
 }
 
+impl ServerSettings {
+    /// Compile `content_binding_allow_regex`, if set, once for reuse across
+    /// many checks instead of recompiling per request.
+    ///
+    /// Wraps the configured pattern in `^(?:...)$` so it's anchored to the
+    /// whole `content_binding` rather than merely matching a substring
+    /// somewhere within it, matching the "must fully match" behavior this
+    /// field documents.
+    ///
+    /// Returns `Ok(None)` when unset. [`Settings::validate`] already rejects
+    /// an invalid pattern for settings loaded through
+    /// [`crate::config::loader`], so a caller downstream of that can treat
+    /// an `Err` here as unreachable in practice.
+    pub fn compile_content_binding_allow_regex(&self) -> crate::Result<Option<regex::Regex>> {
+        self.content_binding_allow_regex
+            .as_deref()
+            .map(|pattern| {
+                regex::Regex::new(&format!("^(?:{pattern})$")).map_err(|e| {
+                    crate::Error::config(
+                        "content_binding_allow_regex",
+                        &format!("Invalid regex '{}': {}", pattern, e),
+                    )
+                })
+            })
+            .transpose()
+    }
+}
+
 impl Default for TokenSettings {
     fn default() -> Self {
         Self {
             ttl_hours: 6,
+            ttl_jitter_secs: default_ttl_jitter_secs(),
             enable_cache: default_true(),
             max_cache_entries: default_max_cache_entries(),
             cache_cleanup_interval: default_cache_cleanup_interval(),
             pot_cache_duration: default_pot_cache_duration(),
             pot_generation_timeout: default_pot_generation_timeout(),
+            mint_refresh_threshold_secs: default_mint_refresh_threshold_secs(),
+            max_minter_entries: default_max_minter_entries(),
+            min_remaining_secs: 0,
+            stale_while_revalidate_secs: 0,
+            default_content_binding: None,
+            max_lifetime_secs: None,
+            serve_stale_on_error: false,
         }
     }
 }
@@ -300,6 +691,8 @@ impl Default for LoggingSettings {
             verbose: false,
             format: default_log_format(),
             log_requests: default_true(),
+            audit_file: None,
+            hash_content_bindings: false,
         }
     }
 }
@@ -315,6 +708,10 @@ impl Default for NetworkSettings {
             max_retries: default_max_retries(),
             retry_interval: default_retry_interval(),
             user_agent: default_user_agent(),
+            proxy_pool: Vec::new(),
+            user_agent_pool: Vec::new(),
+            innertube_base_url: None,
+            innertube_client_name: InnertubeClientName::default(),
         }
     }
 }
@@ -332,8 +729,11 @@ impl Default for BotGuardSettings {
                     .join("bgutil-pot")
                     .join("botguard_snapshot.bin"),
             ),
+            snapshot_read_path: None,
             user_agent: None, // Use rustypipe-botguard default
             disable_snapshot: false,
+            eager_init: default_true(),
+            eager_init_timeout_secs: default_eager_init_timeout_secs(),
         }
     }
 }
@@ -424,6 +824,65 @@ impl Settings {
         Ok(settings)
     }
 
+    /// Expand `${VAR}` references in string-typed settings using environment
+    /// variable values
+    ///
+    /// Lets a config file reference secrets (e.g. `https_proxy = "${CORP_PROXY}"`)
+    /// without committing them. Meant to run once, right after loading a
+    /// config file and before `merge_with_env`, so an explicit environment
+    /// variable override still wins outright. Errors if a referenced
+    /// variable isn't set; a bare `$` not followed by `{` is left untouched.
+    pub fn expand_env_vars(mut self) -> crate::Result<Self> {
+        self.server.host = expand_env_var_refs("server.host", &self.server.host)?;
+        for origin in &mut self.server.cors_allowed_origins {
+            *origin = expand_env_var_refs("server.cors_allowed_origins", origin)?;
+        }
+        if let Some(auth_token) = &self.server.auth_token {
+            self.server.auth_token = Some(expand_env_var_refs("server.auth_token", auth_token)?);
+        }
+
+        self.logging.level = expand_env_var_refs("logging.level", &self.logging.level)?;
+        self.logging.format = expand_env_var_refs("logging.format", &self.logging.format)?;
+
+        if let Some(https_proxy) = &self.network.https_proxy {
+            self.network.https_proxy =
+                Some(expand_env_var_refs("network.https_proxy", https_proxy)?);
+        }
+        if let Some(http_proxy) = &self.network.http_proxy {
+            self.network.http_proxy =
+                Some(expand_env_var_refs("network.http_proxy", http_proxy)?);
+        }
+        if let Some(all_proxy) = &self.network.all_proxy {
+            self.network.all_proxy = Some(expand_env_var_refs("network.all_proxy", all_proxy)?);
+        }
+        self.network.user_agent =
+            expand_env_var_refs("network.user_agent", &self.network.user_agent)?;
+        for proxy in &mut self.network.proxy_pool {
+            *proxy = expand_env_var_refs("network.proxy_pool", proxy)?;
+        }
+        if let Some(base_url) = &self.network.innertube_base_url {
+            self.network.innertube_base_url =
+                Some(expand_env_var_refs("network.innertube_base_url", base_url)?);
+        }
+
+        self.botguard.request_key =
+            expand_env_var_refs("botguard.request_key", &self.botguard.request_key)?;
+        if let Some(endpoint) = &self.botguard.challenge_endpoint {
+            self.botguard.challenge_endpoint =
+                Some(expand_env_var_refs("botguard.challenge_endpoint", endpoint)?);
+        }
+        if let Some(user_agent) = &self.botguard.user_agent {
+            self.botguard.user_agent =
+                Some(expand_env_var_refs("botguard.user_agent", user_agent)?);
+        }
+
+        if let Some(cache_dir) = &self.cache.cache_dir {
+            self.cache.cache_dir = Some(expand_env_var_refs("cache.cache_dir", cache_dir)?);
+        }
+
+        Ok(self)
+    }
+
     /// Merge settings with environment variable overrides
     pub fn merge_with_env(mut self) -> crate::Result<Self> {
         let env_settings = Self::from_env()?;
@@ -496,6 +955,9 @@ impl Settings {
             }
         }
 
+        // Validate the content binding allowlist regex, if present
+        self.server.compile_content_binding_allow_regex()?;
+
         // Validate proxy URLs if present
         for (name, proxy_url) in [
             ("https_proxy", &self.network.https_proxy),
@@ -534,12 +996,17 @@ mod tests {
         assert_eq!(settings.server.host, "::");
         assert_eq!(settings.server.port, 4416);
         assert_eq!(settings.token.ttl_hours, 6);
+        assert_eq!(settings.token.ttl_jitter_secs, 0);
         assert!(settings.token.enable_cache);
         assert_eq!(settings.botguard.request_key, "O43z0dpjhgX20SCx4KAo");
 
         // Test new POT-specific settings
         assert_eq!(settings.token.pot_cache_duration, 1800);
         assert_eq!(settings.token.pot_generation_timeout, 30);
+        assert_eq!(settings.token.max_minter_entries, usize::MAX);
+        assert_eq!(settings.server.unix_socket, None);
+        assert!(settings.server.cors_allowed_origins.is_empty());
+        assert!(!settings.server.enable_compression);
     }
 
     #[test]
@@ -628,4 +1095,32 @@ ttl_hours = 12
         settings.network.https_proxy = Some("invalid-url".to_string());
         assert!(settings.validate().is_err());
     }
+
+    #[test]
+    fn test_validation_invalid_content_binding_allow_regex() {
+        let mut settings = Settings::default();
+        settings.server.content_binding_allow_regex = Some("(unclosed".to_string());
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_validation_valid_content_binding_allow_regex() {
+        let mut settings = Settings::default();
+        settings.server.content_binding_allow_regex = Some("^[A-Za-z0-9_-]{11}$".to_string());
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_compile_content_binding_allow_regex_anchors_unanchored_patterns() {
+        let mut settings = Settings::default();
+        settings.server.content_binding_allow_regex = Some("[A-Za-z0-9_-]{11}".to_string());
+        let regex = settings
+            .server
+            .compile_content_binding_allow_regex()
+            .unwrap()
+            .unwrap();
+
+        assert!(regex.is_match("dQw4w9WgXcQ"));
+        assert!(!regex.is_match("prefix-dQw4w9WgXcQ-suffix"));
+    }
 }
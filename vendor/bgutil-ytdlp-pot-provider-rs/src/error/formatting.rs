@@ -99,6 +99,10 @@ pub fn format_error_with_update(error: &Error, update: bool) -> String {
             None => format!("Validation failed for {}: {}", field, message),
         },
 
+        Error::NotInitialized { component } => {
+            format!("{} not initialized", component)
+        }
+
         // For standard errors, use their Display implementation
         _ => error.to_string(),
     };
@@ -201,6 +205,14 @@ mod tests {
         assert!(formatted.contains("VM execution failed"));
     }
 
+    #[test]
+    fn test_not_initialized_error_formatting() {
+        let error = Error::not_initialized("BotGuard client");
+        let formatted = format_error(&error);
+
+        assert_eq!(formatted, "BotGuard client not initialized");
+    }
+
     #[test]
     fn test_config_error_formatting() {
         let error = Error::config("proxy_url", "Invalid URL format");
@@ -149,6 +149,13 @@ pub enum Error {
         value: Option<String>,
     },
 
+    /// Component used before it was initialized
+    #[error("{component} not initialized")]
+    NotInitialized {
+        /// The component that was used before being initialized
+        component: String,
+    },
+
     /// Generic internal errors
     #[error("Internal error: {message}")]
     Internal {
@@ -337,6 +344,30 @@ impl Error {
         }
     }
 
+    /// Create a validation error that also records the offending value
+    pub fn validation_with_value<S: Into<String>>(field: S, message: S, value: S) -> Self {
+        Self::Validation {
+            field: field.into(),
+            message: message.into(),
+            value: Some(value.into()),
+        }
+    }
+
+    /// Create a rate limit error
+    pub fn rate_limit<S: Into<String>>(message: S, retry_after: Option<u64>) -> Self {
+        Self::RateLimit {
+            message: message.into(),
+            retry_after,
+        }
+    }
+
+    /// Create a not-initialized error
+    pub fn not_initialized<S: Into<String>>(component: S) -> Self {
+        Self::NotInitialized {
+            component: component.into(),
+        }
+    }
+
     /// Create an internal error
     pub fn internal<S: Into<String>>(message: S) -> Self {
         Self::Internal {
@@ -377,6 +408,7 @@ impl Error {
             Error::Auth { .. } => "auth",
             Error::RateLimit { .. } => "rate_limit",
             Error::Validation { .. } => "validation",
+            Error::NotInitialized { .. } => "init",
             Error::Internal { .. } => "internal",
             // Legacy variants
             Error::ConfigLegacy(..) => "config",
@@ -545,6 +577,14 @@ mod tests {
         assert!(err.to_string().contains("Proxy error"));
     }
 
+    #[test]
+    fn test_not_initialized_error() {
+        let err = Error::not_initialized("BotGuard client");
+        assert!(matches!(err, Error::NotInitialized { .. }));
+        assert_eq!(err.to_string(), "BotGuard client not initialized");
+        assert_eq!(err.category(), "init");
+    }
+
     #[test]
     fn test_date_parse_error() {
         let date_err = chrono::DateTime::parse_from_rfc3339("invalid date");
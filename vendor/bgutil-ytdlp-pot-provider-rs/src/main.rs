@@ -15,6 +15,16 @@
 //! bgutil-pot --content-binding "video_id" --verbose
 //! ```
 //!
+//! ## Doctor Mode
+//! ```bash
+//! bgutil-pot doctor
+//! ```
+//!
+//! ## Benchmark Mode
+//! ```bash
+//! bgutil-pot benchmark --requests 100 --concurrency 10
+//! ```
+//!
 //! ## Help and Version
 //! ```bash
 //! bgutil-pot --version
@@ -25,19 +35,24 @@
 use clap::{Parser, Subcommand};
 
 use bgutil_ytdlp_pot_provider::cli::{
+    benchmark::{BenchmarkArgs, run_benchmark_mode},
+    doctor::{DoctorArgs, run_doctor_mode},
     generate::{GenerateArgs, run_generate_mode},
     server::{ServerArgs, run_server_mode},
 };
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
+#[command(long_version = bgutil_ytdlp_pot_provider::utils::version::get_detailed_version())]
 #[command(name = "bgutil-pot")]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 
     // Generate mode options (when no subcommand is provided)
-    /// Content binding (video ID, visitor data, etc.)
+    /// Content binding (video ID, visitor data, etc.). Pass `-` to read it
+    /// from stdin instead, so scripts don't have to expose it on the
+    /// command line.
     #[arg(
         short,
         long,
@@ -70,9 +85,32 @@ struct Cli {
     #[arg(long)]
     disable_tls_verification: bool,
 
+    /// Overall deadline in seconds for token generation, after which the process exits with an error
+    #[arg(long, value_name = "SECS")]
+    timeout: Option<u64>,
+
     /// Enable verbose logging
     #[arg(long)]
     verbose: bool,
+
+    /// Read newline-delimited content bindings from this file and mint one
+    /// token per line, writing a JSONL stream (one result object per line)
+    /// instead of a single JSON response. A failing line is reported as an
+    /// error object rather than aborting the remaining lines.
+    #[arg(long, value_name = "PATH")]
+    batch_file: Option<String>,
+
+    /// Pretty-print the JSON written to stdout for a single request, instead
+    /// of the default compact single-line form. Ignored in batch mode.
+    #[arg(long)]
+    pretty: bool,
+
+    /// Write the JSON response to this named pipe (FIFO) instead of stdout,
+    /// creating the FIFO if it doesn't already exist. Useful for a
+    /// long-lived generator feeding yt-dlp, where a plain stdout pipe can
+    /// have buffering issues. Unix only.
+    #[arg(long, value_name = "PATH")]
+    fifo: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -87,10 +125,47 @@ enum Commands {
         #[arg(long)]
         host: Option<String>,
 
+        /// Configuration file path; repeatable to layer files, with later
+        /// ones deep-merged over earlier ones (e.g. a base config plus an
+        /// environment-specific overlay)
+        #[arg(long)]
+        config: Vec<String>,
+
+        /// Enable verbose logging
+        #[arg(short, long)]
+        verbose: bool,
+
+        /// Validate settings and resolve the bind address, then exit
+        /// without binding a port or initializing BotGuard
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Run diagnostic checks to verify the setup works
+    Doctor {
+        /// Configuration file path
+        #[arg(long)]
+        config: Option<String>,
+
+        /// Enable verbose logging
+        #[arg(short, long)]
+        verbose: bool,
+    },
+
+    /// Measure local token minting throughput and latency
+    Benchmark {
         /// Configuration file path
         #[arg(long)]
         config: Option<String>,
 
+        /// Number of tokens to mint
+        #[arg(long, default_value = "100")]
+        requests: u32,
+
+        /// Number of mints to run concurrently
+        #[arg(long, default_value = "10")]
+        concurrency: u32,
+
         /// Enable verbose logging
         #[arg(short, long)]
         verbose: bool,
@@ -107,6 +182,7 @@ async fn main() -> anyhow::Result<()> {
             host,
             config,
             verbose,
+            dry_run,
         }) => {
             // Server mode logic
             let args = ServerArgs {
@@ -114,9 +190,28 @@ async fn main() -> anyhow::Result<()> {
                 host,
                 config,
                 verbose,
+                dry_run,
             };
             run_server_mode(args).await
         }
+        Some(Commands::Doctor { config, verbose }) => {
+            let args = DoctorArgs { config, verbose };
+            run_doctor_mode(args).await
+        }
+        Some(Commands::Benchmark {
+            config,
+            requests,
+            concurrency,
+            verbose,
+        }) => {
+            let args = BenchmarkArgs {
+                config,
+                requests,
+                concurrency,
+                verbose,
+            };
+            run_benchmark_mode(args).await
+        }
         None => {
             // Generate mode logic (default when no subcommand)
             let args = GenerateArgs {
@@ -127,8 +222,12 @@ async fn main() -> anyhow::Result<()> {
                 bypass_cache: cli.bypass_cache,
                 source_address: cli.source_address,
                 disable_tls_verification: cli.disable_tls_verification,
+                timeout: cli.timeout,
                 version: false, // Version is handled by clap itself
                 verbose: cli.verbose,
+                batch_file: cli.batch_file,
+                pretty: cli.pretty,
+                fifo: cli.fifo,
             };
             run_generate_mode(args).await
         }
@@ -157,12 +256,103 @@ mod tests {
             }) => {
                 assert_eq!(port, Some(8080));
                 assert_eq!(host, Some("0.0.0.0".to_string()));
-                assert_eq!(config, None);
+                assert!(config.is_empty());
+            }
+            _ => panic!("Expected server subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_server_subcommand_layered_config() {
+        let cli = Cli::parse_from(&[
+            "bgutil-pot",
+            "server",
+            "--config",
+            "base.toml",
+            "--config",
+            "overlay.toml",
+        ]);
+
+        match cli.command {
+            Some(Commands::Server { config, .. }) => {
+                assert_eq!(
+                    config,
+                    vec!["base.toml".to_string(), "overlay.toml".to_string()]
+                );
+            }
+            _ => panic!("Expected server subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_server_subcommand_dry_run_flag() {
+        let cli = Cli::parse_from(&["bgutil-pot", "server", "--dry-run"]);
+
+        match cli.command {
+            Some(Commands::Server { dry_run, .. }) => {
+                assert!(dry_run);
             }
             _ => panic!("Expected server subcommand"),
         }
     }
 
+    #[test]
+    fn test_doctor_subcommand() {
+        let cli = Cli::parse_from(&["bgutil-pot", "doctor", "--config", "custom.toml"]);
+
+        match cli.command {
+            Some(Commands::Doctor { config, verbose }) => {
+                assert_eq!(config, Some("custom.toml".to_string()));
+                assert!(!verbose);
+            }
+            _ => panic!("Expected doctor subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_benchmark_subcommand() {
+        let cli = Cli::parse_from(&[
+            "bgutil-pot",
+            "benchmark",
+            "--requests",
+            "50",
+            "--concurrency",
+            "5",
+        ]);
+
+        match cli.command {
+            Some(Commands::Benchmark {
+                config,
+                requests,
+                concurrency,
+                verbose,
+            }) => {
+                assert!(config.is_none());
+                assert_eq!(requests, 50);
+                assert_eq!(concurrency, 5);
+                assert!(!verbose);
+            }
+            _ => panic!("Expected benchmark subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_benchmark_subcommand_default_values() {
+        let cli = Cli::parse_from(&["bgutil-pot", "benchmark"]);
+
+        match cli.command {
+            Some(Commands::Benchmark {
+                requests,
+                concurrency,
+                ..
+            }) => {
+                assert_eq!(requests, 100);
+                assert_eq!(concurrency, 10);
+            }
+            _ => panic!("Expected benchmark subcommand"),
+        }
+    }
+
     #[test]
     fn test_generate_mode() {
         let cli = Cli::parse_from(&["bgutil-pot", "--content-binding", "test", "--verbose"]);
@@ -192,11 +382,13 @@ mod tests {
                 host,
                 config,
                 verbose,
+                dry_run,
             }) => {
                 assert_eq!(port, None);
                 assert_eq!(host, None);
-                assert_eq!(config, None);
+                assert!(config.is_empty());
                 assert!(!verbose);
+                assert!(!dry_run);
             }
             _ => panic!("Expected server subcommand"),
         }
@@ -208,7 +400,7 @@ mod tests {
 
         match cli.command {
             Some(Commands::Server { config, .. }) => {
-                assert_eq!(config, Some("/path/to/config.toml".to_string()));
+                assert_eq!(config, vec!["/path/to/config.toml".to_string()]);
             }
             _ => panic!("Expected server subcommand"),
         }
@@ -241,4 +433,52 @@ mod tests {
         assert!(cli.command.is_none());
         assert_eq!(cli.content_binding, Some("-6OjhRWNLfk".to_string()));
     }
+
+    #[test]
+    fn test_generate_timeout_option() {
+        let cli = Cli::parse_from(&["bgutil-pot", "--content-binding", "test", "--timeout", "10"]);
+
+        assert_eq!(cli.timeout, Some(10));
+    }
+
+    #[test]
+    fn test_generate_timeout_defaults_to_none() {
+        let cli = Cli::parse_from(&["bgutil-pot", "--content-binding", "test"]);
+
+        assert_eq!(cli.timeout, None);
+    }
+
+    #[test]
+    fn test_pretty_flag_defaults_to_false() {
+        let cli = Cli::parse_from(&["bgutil-pot", "--content-binding", "test"]);
+
+        assert!(!cli.pretty);
+    }
+
+    #[test]
+    fn test_pretty_flag_enabled() {
+        let cli = Cli::parse_from(&["bgutil-pot", "--content-binding", "test", "--pretty"]);
+
+        assert!(cli.pretty);
+    }
+
+    #[test]
+    fn test_fifo_flag_defaults_to_none() {
+        let cli = Cli::parse_from(&["bgutil-pot", "--content-binding", "test"]);
+
+        assert_eq!(cli.fifo, None);
+    }
+
+    #[test]
+    fn test_fifo_flag_sets_path() {
+        let cli = Cli::parse_from(&[
+            "bgutil-pot",
+            "--content-binding",
+            "test",
+            "--fifo",
+            "/tmp/pot.fifo",
+        ]);
+
+        assert_eq!(cli.fifo, Some("/tmp/pot.fifo".to_string()));
+    }
 }
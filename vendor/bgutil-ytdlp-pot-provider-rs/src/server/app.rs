@@ -2,14 +2,22 @@
 //!
 //! Creates and configures the Axum application with routes and middleware.
 
-use crate::{config::Settings, session::SessionManager};
+use crate::{
+    config::{ServerSettings, Settings},
+    session::SessionManager,
+};
 use axum::{
     Router, middleware,
-    routing::{get, post},
+    routing::{delete, get, post},
 };
 use std::sync::Arc;
 use tower::ServiceBuilder;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use tower_http::{
+    compression::CompressionLayer,
+    cors::{Any, CorsLayer},
+    decompression::RequestDecompressionLayer,
+    trace::TraceLayer,
+};
 
 /// Application state shared across handlers
 #[derive(Clone)]
@@ -20,48 +28,305 @@ pub struct AppState {
     pub settings: Arc<Settings>,
     /// Server start time for uptime calculation
     pub start_time: std::time::Instant,
+    /// Cached result of the last `GET /health?deep=true` throwaway mint
+    pub deep_health_cache: Arc<super::handlers::DeepHealthCache>,
+    /// Successful `/get_pot` responses keyed by `Idempotency-Key`
+    pub idempotency_cache: Arc<super::handlers::IdempotencyCache>,
+    /// Compiled form of `server.content_binding_allow_regex`, built once
+    /// here rather than per-request. Loading settings through
+    /// [`crate::config::loader`] already ran [`Settings::validate`], which
+    /// rejects an invalid pattern, so compiling again here is expected to
+    /// always succeed.
+    pub content_binding_allow_regex: Option<Arc<regex::Regex>>,
 }
 
 /// Create the main Axum application with routes and middleware
-pub fn create_app(settings: Settings) -> Router {
-    let session_manager = Arc::new(SessionManager::new(settings.clone()));
+pub fn create_app(settings: Settings) -> crate::Result<Router> {
+    let session_manager = SessionManager::new_shared(settings.clone());
+    create_app_with_session_manager(settings, session_manager)
+}
+
+/// Create the main Axum application from an already-constructed session manager
+///
+/// Used by [`crate::cli::server::run_server_mode`] when BotGuard is initialized
+/// eagerly at startup, so the manager the handlers see is the same one that was
+/// warmed up before the listener started accepting connections.
+///
+/// Returns an error if `settings.server.content_binding_allow_regex` doesn't
+/// compile. Callers that went through [`crate::config::loader`] already had
+/// this validated by [`Settings::validate`], but `settings` is public API
+/// that can be constructed directly, so this is a real, reachable error here.
+pub fn create_app_with_session_manager(
+    settings: Settings,
+    session_manager: Arc<SessionManager>,
+) -> crate::Result<Router> {
+    let content_binding_allow_regex = settings
+        .server
+        .compile_content_binding_allow_regex()?
+        .map(Arc::new);
 
     let state = AppState {
         session_manager,
         settings: Arc::new(settings),
         start_time: std::time::Instant::now(),
+        deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+        idempotency_cache: Arc::new(dashmap::DashMap::new()),
+        content_binding_allow_regex,
     };
 
-    Router::new()
+    let enable_compression = state.settings.server.enable_compression;
+
+    let mut router = Router::new()
         .route("/get_pot", post(super::handlers::generate_pot))
-        .layer(middleware::from_fn(
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
             super::handlers::validate_deprecated_fields_middleware,
         ))
         .route("/ping", get(super::handlers::ping))
+        .route("/ready", get(super::handlers::ready))
+        .route("/health", get(super::handlers::health))
+        .route("/version", get(super::handlers::version_info))
+        .route("/openapi.json", get(super::handlers::openapi_spec))
+        .route("/metrics", get(super::handlers::metrics))
+        .route("/cache/stats", get(super::handlers::cache_stats))
+        .route("/cache/prune", post(super::handlers::cache_prune))
+        .route("/diagnostics", get(super::handlers::diagnostics))
         .route(
             "/invalidate_caches",
             post(super::handlers::invalidate_caches),
         )
         .route("/invalidate_it", post(super::handlers::invalidate_it))
         .route("/minter_cache", get(super::handlers::minter_cache))
+        .route(
+            "/minter_cache/detail",
+            get(super::handlers::minter_cache_detail),
+        )
+        .route("/cache/entries", get(super::handlers::cache_entries))
+        .route(
+            "/cache/entries/{binding}",
+            delete(super::handlers::delete_cache_entry),
+        )
+        .route("/warmup", post(super::handlers::warmup))
+        .route("/reinitialize", post(super::handlers::reinitialize))
+        .route("/config", get(super::handlers::config))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            super::handlers::pretty_print_errors_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            super::handlers::access_log_middleware,
+        ))
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
-                .layer(CorsLayer::permissive()),
-        )
-        .with_state(state)
+                .layer(build_cors_layer(&state.settings.server))
+                .layer(RequestDecompressionLayer::new()),
+        );
+
+    if enable_compression {
+        router = router.layer(CompressionLayer::new());
+    }
+
+    Ok(router.with_state(state))
+}
+
+/// Build the CORS layer from server settings
+///
+/// - `enable_cors = false` disables CORS entirely (no `Access-Control-*`
+///   headers on any response).
+/// - `enable_cors = true` with an empty `cors_allowed_origins` reflects any
+///   origin, matching the previous always-permissive behavior.
+/// - `enable_cors = true` with a non-empty `cors_allowed_origins` restricts
+///   `Access-Control-Allow-Origin` to exactly those origins.
+fn build_cors_layer(server_settings: &ServerSettings) -> CorsLayer {
+    if !server_settings.enable_cors {
+        return CorsLayer::new();
+    }
+
+    if server_settings.cors_allowed_origins.is_empty() {
+        return CorsLayer::permissive();
+    }
+
+    let allowed_origins: Vec<_> = server_settings
+        .cors_allowed_origins
+        .iter()
+        .filter_map(|origin| origin.parse().ok())
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(allowed_origins)
+        .allow_methods(Any)
+        .allow_headers(Any)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{PotRequest, PotResponse};
+    use axum::http::{Request, StatusCode, header};
+    use tower::ServiceExt;
 
     #[test]
     fn test_create_app() {
         let settings = Settings::default();
-        let _app = create_app(settings);
+        let _app = create_app(settings).unwrap();
 
         // Test passes if create_app doesn't panic during Router construction
         // The Router type itself validates correct configuration at compile time
     }
+
+    #[test]
+    fn test_create_app_returns_error_instead_of_panicking_on_invalid_regex() {
+        // Settings built directly by a library consumer never goes through
+        // `Settings::validate`, so an invalid pattern here has to surface as
+        // an `Err` rather than a panic.
+        let mut settings = Settings::default();
+        settings.server.content_binding_allow_regex = Some("(unclosed".to_string());
+
+        assert!(create_app(settings).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_allowed_origin_receives_cors_header() {
+        let mut settings = Settings::default();
+        settings.server.cors_allowed_origins = vec!["https://allowed.example".to_string()];
+        let app = create_app(settings).unwrap();
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header(header::ORIGIN, "https://allowed.example")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(|value| value.to_str().unwrap()),
+            Some("https://allowed.example")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_disallowed_origin_receives_no_cors_header() {
+        let mut settings = Settings::default();
+        settings.server.cors_allowed_origins = vec!["https://allowed.example".to_string()];
+        let app = create_app(settings).unwrap();
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header(header::ORIGIN, "https://evil.example")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compression_enabled_returns_gzip_encoded_response() {
+        let mut settings = Settings::default();
+        settings.server.enable_compression = true;
+        let app = create_app(settings).unwrap();
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(
+            response
+                .headers()
+                .get(header::CONTENT_ENCODING)
+                .map(|value| value.to_str().unwrap()),
+            Some("gzip")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compression_disabled_by_default() {
+        let settings = Settings::default();
+        let app = create_app(settings).unwrap();
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header(header::ACCEPT_ENCODING, "gzip")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_pot_accepts_gzip_encoded_request_body() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let settings = Settings::default();
+        let app = create_app(settings).unwrap();
+
+        let request_body =
+            serde_json::to_vec(&PotRequest::new().with_content_binding("test_video")).unwrap();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&request_body).unwrap();
+        let gzipped_body = encoder.finish().unwrap();
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/get_pot")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::CONTENT_ENCODING, "gzip")
+            .body(axum::body::Body::from(gzipped_body))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let pot_response: PotResponse = serde_json::from_slice(&body).unwrap();
+        assert!(!pot_response.po_token.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cors_disabled_has_no_header_even_for_any_origin() {
+        let mut settings = Settings::default();
+        settings.server.enable_cors = false;
+        let app = create_app(settings).unwrap();
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header(header::ORIGIN, "https://anything.example")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .is_none()
+        );
+    }
 }
@@ -0,0 +1,156 @@
+//! Client IP resolution behind a trusted reverse proxy
+//!
+//! A TCP peer sitting behind a reverse proxy (nginx, an ALB, etc.) is the
+//! proxy itself, so rate limiting and access logs would otherwise see the
+//! proxy's address for every client. This module lets a deployment list the
+//! proxies it trusts by CIDR block in `server.trusted_proxies`, and only
+//! then honors the client address the proxy reports via `X-Forwarded-For`
+//! - an untrusted peer can never spoof its own address by sending the
+//! header itself.
+
+use std::net::IpAddr;
+
+/// Resolve the real client IP for a request.
+///
+/// Returns `peer`'s address unless `peer` falls within one of
+/// `trusted_proxies`, in which case the left-most address in `forwarded_for`
+/// (the original client, per the usual `X-Forwarded-For` convention of
+/// appending each hop) is used instead, falling back to `peer` if the header
+/// is absent or unparseable. Entries in `trusted_proxies` that aren't valid
+/// CIDR notation are ignored rather than treated as a configuration error.
+pub fn resolve_client_ip(
+    peer: IpAddr,
+    forwarded_for: Option<&str>,
+    trusted_proxies: &[String],
+) -> IpAddr {
+    if !is_trusted_proxy(peer, trusted_proxies) {
+        return peer;
+    }
+
+    forwarded_for
+        .and_then(|header| header.split(',').next())
+        .and_then(|first| first.trim().parse().ok())
+        .unwrap_or(peer)
+}
+
+/// Whether `peer` falls within any of `trusted_proxies`
+fn is_trusted_proxy(peer: IpAddr, trusted_proxies: &[String]) -> bool {
+    trusted_proxies
+        .iter()
+        .filter_map(|cidr| Cidr::parse(cidr))
+        .any(|cidr| cidr.contains(peer))
+}
+
+/// A parsed IPv4 or IPv6 CIDR block
+#[derive(Debug, Clone, Copy)]
+struct Cidr {
+    network: IpAddr,
+    prefix_len: u32,
+}
+
+impl Cidr {
+    fn parse(cidr: &str) -> Option<Self> {
+        let (addr, prefix_len) = cidr.split_once('/')?;
+        let network: IpAddr = addr.parse().ok()?;
+        let prefix_len: u32 = prefix_len.parse().ok()?;
+        let max_prefix_len = match network {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        if prefix_len > max_prefix_len {
+            return None;
+        }
+        Some(Self {
+            network,
+            prefix_len,
+        })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix_len).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix_len).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trusted_peer_honors_forwarded_for() {
+        let peer: IpAddr = "10.0.0.5".parse().unwrap();
+        let trusted_proxies = vec!["10.0.0.0/8".to_string()];
+
+        let resolved = resolve_client_ip(peer, Some("203.0.113.9, 10.0.0.5"), &trusted_proxies);
+
+        assert_eq!(resolved, "203.0.113.9".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn test_untrusted_peer_ignores_forwarded_for() {
+        let peer: IpAddr = "198.51.100.1".parse().unwrap();
+        let trusted_proxies = vec!["10.0.0.0/8".to_string()];
+
+        let resolved = resolve_client_ip(peer, Some("203.0.113.9"), &trusted_proxies);
+
+        assert_eq!(resolved, peer);
+    }
+
+    #[test]
+    fn test_empty_trusted_proxies_always_uses_peer() {
+        let peer: IpAddr = "10.0.0.5".parse().unwrap();
+
+        let resolved = resolve_client_ip(peer, Some("203.0.113.9"), &[]);
+
+        assert_eq!(resolved, peer);
+    }
+
+    #[test]
+    fn test_trusted_peer_without_header_falls_back_to_peer() {
+        let peer: IpAddr = "10.0.0.5".parse().unwrap();
+        let trusted_proxies = vec!["10.0.0.0/8".to_string()];
+
+        let resolved = resolve_client_ip(peer, None, &trusted_proxies);
+
+        assert_eq!(resolved, peer);
+    }
+
+    #[test]
+    fn test_trusted_peer_with_unparseable_header_falls_back_to_peer() {
+        let peer: IpAddr = "10.0.0.5".parse().unwrap();
+        let trusted_proxies = vec!["10.0.0.0/8".to_string()];
+
+        let resolved = resolve_client_ip(peer, Some("not-an-ip"), &trusted_proxies);
+
+        assert_eq!(resolved, peer);
+    }
+
+    #[test]
+    fn test_cidr_v6_match() {
+        let peer: IpAddr = "2001:db8::1".parse().unwrap();
+        let trusted_proxies = vec!["2001:db8::/32".to_string()];
+
+        let resolved = resolve_client_ip(peer, Some("203.0.113.9"), &trusted_proxies);
+
+        assert_eq!(resolved, "203.0.113.9".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_cidr_entry_is_ignored() {
+        let peer: IpAddr = "10.0.0.5".parse().unwrap();
+        let trusted_proxies = vec!["not-a-cidr".to_string()];
+
+        let resolved = resolve_client_ip(peer, Some("203.0.113.9"), &trusted_proxies);
+
+        assert_eq!(resolved, peer);
+    }
+}
@@ -3,21 +3,86 @@
 //! Implementation of HTTP endpoints for the POT provider server.
 
 use crate::{
+    config::{DeprecatedFieldPolicy, Settings},
     server::app::AppState,
-    types::{ErrorResponse, PingResponse, PotRequest},
-    utils::version,
+    session::network::redact_proxy_credentials,
+    types::{
+        CachePruneResponse, CacheStatsResponse, DeepHealthResponse, DiagnosticsResponse,
+        ErrorResponse, MinterCacheDetailEntry, PingResponse, PotRequest, PotResponse,
+        ReinitializeResponse, SessionCacheEntry, VersionResponse, WarmupRequest, WarmupResponse,
+    },
+    utils::{etag::weak_etag, version},
 };
 use axum::{
     Json,
     body::Body,
-    extract::{Request, State},
-    http::StatusCode,
+    extract::{Path, Query, Request, State},
+    http::{HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use dashmap::DashMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Maximum number of tokens minted concurrently by `/warmup`
+const WARMUP_MAX_CONCURRENCY: usize = 8;
+
+/// Fixed content binding the deep `/health?deep=true` check mints a
+/// throwaway token for; never served from or written to the normal POT cache
+const DEEP_HEALTH_CONTENT_BINDING: &str = "bgutil-deep-health-check";
+
+/// How long a deep health check result is cached, so a monitoring probe
+/// polling `/health?deep=true` doesn't trigger a BotGuard mint on every call
+const DEEP_HEALTH_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Request header a client can set on `POST /get_pot` to dedupe retried
+/// requests; see [`IdempotencyCache`]
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// How long a `/get_pot` response is kept under its `Idempotency-Key`, long
+/// enough to cover a client's own retry window without holding onto stale
+/// entries indefinitely
+const IDEMPOTENCY_KEY_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Successful `/get_pot` responses keyed by the client-supplied
+/// `Idempotency-Key`, so a retried request with the same key is replayed
+/// instead of triggering a second mint
+pub(crate) type IdempotencyCache = DashMap<String, (std::time::Instant, PotResponse)>;
+
+/// Field names [`PotRequest`] deserializes; anything else in a `/get_pot`
+/// body is an unknown field, handled per [`crate::config::ServerSettings::reject_unknown_fields`]
+const POT_REQUEST_FIELDS: &[&str] = &[
+    "content_binding",
+    "proxy",
+    "bypass_cache",
+    "challenge",
+    "disable_innertube",
+    "disable_tls_verification",
+    "innertube_context",
+    "source_address",
+    "data_sync_id",
+    "video_id",
+    "token_context",
+    "token_type",
+    "cold_start",
+];
 
 /// Middleware to validate deprecated fields before processing
+///
+/// `data_sync_id` is now a first-class [`PotRequest`] field handled by
+/// [`crate::session::manager`] for session-bound mints, so it's no longer
+/// rejected here; only `visitor_data`, whose meaning was ambiguous and fully
+/// superseded by `content_binding`, remains deprecated.
+///
+/// How a deprecated field is handled is controlled by
+/// `server.deprecated_field_policy`:
+/// [`DeprecatedFieldPolicy::Reject`] keeps the historical hard-400 behavior,
+/// [`DeprecatedFieldPolicy::Warn`] (the default) logs and adds a `Warning`
+/// response header before processing the request normally using
+/// `content_binding`, and [`DeprecatedFieldPolicy::Ignore`] does neither.
 pub async fn validate_deprecated_fields_middleware(
+    State(state): State<AppState>,
     request: Request,
     next: Next,
 ) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
@@ -26,6 +91,8 @@ pub async fn validate_deprecated_fields_middleware(
         return Ok(next.run(request).await);
     }
 
+    let policy = state.settings.server.deprecated_field_policy;
+
     // Extract the request body for validation
     let (parts, body) = request.into_parts();
     let body_bytes = match axum::body::to_bytes(body, usize::MAX).await {
@@ -41,30 +108,30 @@ pub async fn validate_deprecated_fields_middleware(
         }
     };
 
+    let mut warning = None;
+
     // Parse JSON to check for deprecated fields
     if let Ok(json_value) = serde_json::from_slice::<serde_json::Value>(&body_bytes)
         && let Some(obj) = json_value.as_object()
+        && obj.contains_key("visitor_data")
     {
-        // Check for data_sync_id
-        if obj.contains_key("data_sync_id") {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::with_context(
-                    "data_sync_id is deprecated, use content_binding instead",
-                    "deprecated_field_validation",
-                )),
-            ));
-        }
-
-        // Check for visitor_data
-        if obj.contains_key("visitor_data") {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::with_context(
-                    "visitor_data is deprecated, use content_binding instead",
-                    "deprecated_field_validation",
-                )),
-            ));
+        const MESSAGE: &str = "visitor_data is deprecated, use content_binding instead";
+
+        match policy {
+            DeprecatedFieldPolicy::Reject => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse::with_context(
+                        MESSAGE,
+                        "deprecated_field_validation",
+                    )),
+                ));
+            }
+            DeprecatedFieldPolicy::Warn => {
+                tracing::warn!("{MESSAGE}");
+                warning = Some(MESSAGE);
+            }
+            DeprecatedFieldPolicy::Ignore => {}
         }
     }
 
@@ -72,7 +139,128 @@ pub async fn validate_deprecated_fields_middleware(
     let new_body = Body::from(body_bytes);
     let new_request = Request::from_parts(parts, new_body);
 
-    Ok(next.run(new_request).await)
+    let mut response = next.run(new_request).await;
+    if let Some(message) = warning
+        && let Ok(value) = axum::http::HeaderValue::from_str(&format!("199 - \"{message}\""))
+    {
+        response.headers_mut().insert(axum::http::header::WARNING, value);
+    }
+
+    Ok(response)
+}
+
+/// Middleware that logs method, path, status, and latency for each request
+///
+/// Gated on `logging.log_requests` so deployments that prefer the
+/// `TraceLayer` span output (or none at all) can opt out without a restart
+/// of the surrounding tracing subscriber. Query strings are intentionally
+/// omitted from the log line since they may carry a `content_binding`.
+///
+/// Also logs the client address from `ConnectInfo`, when present - that's
+/// only the case for TCP connections served with
+/// `server.accept_proxy_protocol` enabled (see
+/// [`crate::server::proxy_listener`]); it's read directly from the request
+/// extensions rather than as an extractor so this middleware doesn't fail on
+/// the Unix socket path or a plain TCP listener, neither of which populate it.
+///
+/// When the peer falls within `server.trusted_proxies`, the logged address
+/// is resolved from the request's `X-Forwarded-For` header instead (see
+/// [`crate::server::client_ip`]), so deployments behind a reverse proxy log
+/// the real client rather than the proxy on every line.
+pub async fn access_log_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !state.settings.logging.log_requests {
+        return next.run(request).await;
+    }
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let client_addr = request
+        .extensions()
+        .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+        .map(|info| info.0);
+    let forwarded_for = request
+        .headers()
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let client_addr = client_addr.map(|addr| {
+        let resolved_ip = super::client_ip::resolve_client_ip(
+            addr.ip(),
+            forwarded_for.as_deref(),
+            &state.settings.server.trusted_proxies,
+        );
+        std::net::SocketAddr::new(resolved_ip, addr.port())
+    });
+    let start = std::time::Instant::now();
+
+    let response = next.run(request).await;
+
+    let elapsed_ms = start.elapsed().as_millis();
+    tracing::info!(
+        method = %method,
+        path = %path,
+        client_addr = client_addr.map(|a| a.to_string()),
+        status = response.status().as_u16(),
+        elapsed_ms = elapsed_ms,
+        "request completed"
+    );
+
+    response
+}
+
+/// Middleware to pretty-print the JSON body of error responses
+///
+/// Controlled by `server.pretty_errors`; off by default, since most
+/// consumers are automated clients that don't benefit from the extra
+/// whitespace. Only re-serializes responses with a non-2xx/3xx status and a
+/// `application/json` content type - a successful response's body is left
+/// exactly as its handler produced it.
+pub async fn pretty_print_errors_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !state.settings.server.pretty_errors {
+        return next.run(request).await;
+    }
+
+    let response = next.run(request).await;
+
+    if response.status().is_success() || response.status().is_redirection() {
+        return response;
+    }
+
+    let is_json = response
+        .headers()
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let body_bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::empty()),
+    };
+
+    let pretty = serde_json::from_slice::<serde_json::Value>(&body_bytes)
+        .and_then(|value| serde_json::to_vec_pretty(&value));
+    let body_bytes = match pretty {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::from(body_bytes)),
+    };
+
+    parts.headers.insert(
+        axum::http::header::CONTENT_LENGTH,
+        (body_bytes.len() as u64).into(),
+    );
+    Response::from_parts(parts, Body::from(body_bytes))
 }
 
 /// Generate POT token endpoint
@@ -82,11 +270,28 @@ pub async fn validate_deprecated_fields_middleware(
 /// Generates a new POT token based on the request parameters.
 pub async fn generate_pot(
     State(state): State<AppState>,
+    headers: HeaderMap,
     body: axum::body::Bytes,
 ) -> axum::response::Response {
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned);
+
+    if let Some(key) = &idempotency_key
+        && let Some(cached) = state.idempotency_cache.get(key)
+        && cached.0.elapsed() < IDEMPOTENCY_KEY_TTL
+    {
+        tracing::debug!("Replaying cached /get_pot response for idempotency key {key}");
+        let response = cached.1.clone();
+        drop(cached);
+        let expires_at = response.expires_at;
+        return build_pot_response(&state, response, expires_at, Some(false));
+    }
+
     // Parse JSON with detailed error logging
-    let request: PotRequest = match serde_json::from_slice(&body) {
-        Ok(req) => req,
+    let json_value: serde_json::Value = match serde_json::from_slice(&body) {
+        Ok(value) => value,
         Err(e) => {
             // Log the raw body for debugging (truncate if too long)
             let body_preview = if body.len() > 1000 {
@@ -116,29 +321,216 @@ pub async fn generate_pot(
         }
     };
 
+    let unknown_fields: Vec<&str> = json_value
+        .as_object()
+        .map(|obj| {
+            obj.keys()
+                .map(String::as_str)
+                .filter(|key| !POT_REQUEST_FIELDS.contains(key))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if !unknown_fields.is_empty() {
+        if state.settings.server.reject_unknown_fields {
+            tracing::warn!("Rejecting /get_pot request with unknown fields: {unknown_fields:?}");
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse::with_context(
+                    format!("Unknown fields: {}", unknown_fields.join(", ")),
+                    "unknown_fields",
+                )),
+            )
+                .into_response();
+        }
+        tracing::debug!("Ignoring unknown fields in /get_pot request: {unknown_fields:?}");
+    }
+
+    let request: PotRequest = match serde_json::from_value(json_value) {
+        Ok(req) => req,
+        Err(e) => {
+            tracing::error!("Failed to deserialize request into PotRequest: {}", e);
+
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse::with_context(
+                    format!("Invalid JSON: {}", e),
+                    "json_deserialization",
+                )),
+            )
+                .into_response();
+        }
+    };
+
     tracing::debug!("Received POT generation request: {:?}", request);
 
     // Note: Deprecated field validation is now handled by middleware
 
-    match state.session_manager.generate_pot_token(&request).await {
+    if let Err(e) = request.validate() {
+        tracing::warn!("Rejecting invalid POT request: {}", e);
+        return (
+            status_for_error(&e),
+            Json(ErrorResponse::with_context(
+                format_error(&e),
+                "request_validation",
+            )),
+        )
+            .into_response();
+    }
+
+    if let Some(content_binding) = &request.content_binding
+        && let Some(allow_regex) = &state.content_binding_allow_regex
+        && !allow_regex.is_match(content_binding)
+    {
+        tracing::warn!(
+            "Rejecting /get_pot request for content_binding not in the configured allowlist: {:?}",
+            content_binding
+        );
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::with_context(
+                format!(
+                    "content_binding '{}' does not match the configured allowlist",
+                    content_binding
+                ),
+                "content_binding_allowlist",
+            )),
+        )
+            .into_response();
+    }
+
+    let timeout_secs = state.settings.token.pot_generation_timeout;
+    let generation_result = tokio::time::timeout(
+        std::time::Duration::from_secs(timeout_secs),
+        state.session_manager.generate_pot_token(&request),
+    )
+    .await;
+
+    let generation_result = match generation_result {
+        Ok(result) => result,
+        Err(_) => {
+            tracing::error!("POT generation timed out after {}s", timeout_secs);
+            return (
+                StatusCode::GATEWAY_TIMEOUT,
+                Json(ErrorResponse::with_context(
+                    format!("POT generation timed out after {}s", timeout_secs),
+                    "token_generation",
+                )),
+            )
+                .into_response();
+        }
+    };
+
+    match generation_result {
         Ok(response) => {
             tracing::info!(
                 "Successfully generated POT token for content_binding: {:?}",
                 request.content_binding
             );
-            (StatusCode::OK, Json(response)).into_response()
+
+            if let Some(key) = &idempotency_key {
+                state
+                    .idempotency_cache
+                    .retain(|_, (cached_at, _)| cached_at.elapsed() < IDEMPOTENCY_KEY_TTL);
+                state
+                    .idempotency_cache
+                    .insert(key.clone(), (std::time::Instant::now(), response.clone()));
+            }
+
+            let expires_at = response.expires_at;
+            build_pot_response(&state, response, expires_at, request.bypass_cache)
         }
         Err(e) => {
             tracing::error!("Failed to generate POT token: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::with_context(
-                    format_error(&e),
-                    "token_generation",
-                )),
+            let (status, body) = token_generation_error_response(&e);
+            let mut response = (status, body).into_response();
+
+            if let crate::Error::RateLimit {
+                retry_after: Some(retry_after),
+                ..
+            } = &e
+                && let Ok(value) = axum::http::HeaderValue::from_str(&retry_after.to_string())
+            {
+                response
+                    .headers_mut()
+                    .insert(axum::http::header::RETRY_AFTER, value);
+            }
+
+            response
+        }
+    }
+}
+
+/// Header carrying the HMAC-SHA256 signature over a `/get_pot` response
+/// body, present only when `server.response_signing_key` is configured
+const SIGNATURE_HEADER: axum::http::HeaderName =
+    axum::http::HeaderName::from_static("x-pot-signature");
+
+/// Build the `200` response for a successful `/get_pot` mint or idempotent
+/// replay, setting cache headers and, when
+/// [`crate::config::ServerSettings::response_signing_key`] is configured,
+/// an `X-POT-Signature` header over the serialized body so a client behind
+/// a cache or other untrusted intermediary can verify it wasn't tampered
+/// with in transit.
+fn build_pot_response(
+    state: &AppState,
+    response: PotResponse,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    bypass_cache: Option<bool>,
+) -> Response {
+    let mut http_response = match &state.settings.server.response_signing_key {
+        Some(key) => {
+            let body = serde_json::to_vec(&response).unwrap_or_default();
+            let signature = crate::utils::signature::sign_response_body(key, &body);
+            let mut http_response = (
+                StatusCode::OK,
+                [(axum::http::header::CONTENT_TYPE, "application/json")],
+                body,
             )
-                .into_response()
+                .into_response();
+            if let Ok(value) = axum::http::HeaderValue::from_str(&signature) {
+                http_response.headers_mut().insert(SIGNATURE_HEADER, value);
+            }
+            http_response
         }
+        None => (StatusCode::OK, Json(response)).into_response(),
+    };
+
+    set_cache_headers(&mut http_response, expires_at, bypass_cache);
+    http_response
+}
+
+/// Advertise the token's cacheability to HTTP-layer caches and clients
+///
+/// `bypass_cache` requests asked for a fresh mint, so they shouldn't be
+/// cached downstream either; everything else gets a `max-age` scoped to the
+/// token's remaining lifetime plus a matching `Expires` header.
+fn set_cache_headers(
+    response: &mut axum::response::Response,
+    expires_at: chrono::DateTime<chrono::Utc>,
+    bypass_cache: Option<bool>,
+) {
+    let cache_control = if bypass_cache == Some(true) {
+        "no-store".to_string()
+    } else {
+        let max_age = (expires_at - chrono::Utc::now()).num_seconds().max(0);
+        format!("private, max-age={max_age}")
+    };
+
+    if let Ok(value) = axum::http::HeaderValue::from_str(&cache_control) {
+        response
+            .headers_mut()
+            .insert(axum::http::header::CACHE_CONTROL, value);
+    }
+
+    if bypass_cache != Some(true)
+        && let Ok(value) = axum::http::HeaderValue::from_str(
+            &expires_at.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+        )
+    {
+        response
+            .headers_mut()
+            .insert(axum::http::header::EXPIRES, value);
     }
 }
 
@@ -149,6 +541,40 @@ fn format_error(error: &crate::Error) -> String {
     crate::error::format_error(error)
 }
 
+/// Map an error to the HTTP status code that best describes its cause
+///
+/// A proxy misconfiguration, a validation failure, and a rate limit are
+/// different problems and shouldn't all collapse to a generic 500.
+fn status_for_error(error: &crate::Error) -> StatusCode {
+    match error {
+        crate::Error::Validation { .. } | crate::Error::MissingVideoId => {
+            StatusCode::UNPROCESSABLE_ENTITY
+        }
+        crate::Error::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
+        crate::Error::Timeout { .. } => StatusCode::GATEWAY_TIMEOUT,
+        crate::Error::Proxy { .. } | crate::Error::Config { .. } => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Build the `/get_pot` error response for a token-generation failure
+///
+/// When `error` is [`crate::Error::BotGuard`], includes its structured
+/// `code`/`info` in `details` instead of only the flattened `error` string,
+/// so API clients can branch on the failure without parsing prose.
+fn token_generation_error_response(error: &crate::Error) -> (StatusCode, Json<ErrorResponse>) {
+    let message = format_error(error);
+    let response = match error {
+        crate::Error::BotGuard { code, info, .. } => ErrorResponse::with_context_and_details(
+            message,
+            "token_generation",
+            serde_json::json!({ "code": code, "info": info }),
+        ),
+        _ => ErrorResponse::with_context(message, "token_generation"),
+    };
+    (status_for_error(error), Json(response))
+}
+
 /// Ping endpoint for health checks
 ///
 /// GET /ping
@@ -166,18 +592,159 @@ pub async fn ping(State(state): State<AppState>) -> Json<PingResponse> {
     Json(response)
 }
 
+/// Version endpoint
+///
+/// GET /version
+///
+/// Returns structured build/version information for diagnostics and support
+/// requests, without requiring callers to parse `/ping`'s free-form version.
+pub async fn version_info() -> Json<VersionResponse> {
+    Json(VersionResponse::new(
+        version::get_version(),
+        version::get_git_sha(),
+        version::get_build_timestamp(),
+        version::get_rustypipe_botguard_version(),
+    ))
+}
+
+/// OpenAPI document endpoint
+///
+/// GET /openapi.json
+///
+/// Serves a hand-maintained OpenAPI 3 document describing `/get_pot`,
+/// `/ping`, `/ready`, and the invalidation endpoints, kept next to the
+/// handlers it documents.
+pub async fn openapi_spec() -> Json<serde_json::Value> {
+    Json(
+        serde_json::from_str(include_str!("openapi.json"))
+            .expect("openapi.json must be valid JSON"),
+    )
+}
+
+/// Readiness check endpoint
+///
+/// GET /ready
+///
+/// Returns 200 only once BotGuard has successfully initialized and its
+/// snapshot hasn't expired, so orchestrators don't route traffic to a
+/// server that would fail the first `/get_pot` request. Unlike `/ping`,
+/// this does not attempt to initialize BotGuard itself.
+pub async fn ready(State(state): State<AppState>) -> Response {
+    if state.session_manager.is_ready().await {
+        StatusCode::OK.into_response()
+    } else {
+        tracing::debug!("Readiness check failed: BotGuard not initialized or snapshot expired");
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ErrorResponse::with_context(
+                "BotGuard is not initialized or its snapshot has expired",
+                "readiness_check",
+            )),
+        )
+            .into_response()
+    }
+}
+
+/// Query parameters for `GET /health`
+#[derive(Debug, serde::Deserialize)]
+pub struct HealthQuery {
+    /// Mint a throwaway token to prove BotGuard can actually mint, instead of
+    /// just checking that it has initialized
+    #[serde(default)]
+    deep: bool,
+}
+
+/// Cached result of the last deep health check: how it finished, and when
+pub(crate) type DeepHealthCache =
+    tokio::sync::Mutex<Option<(std::time::Instant, Result<u64, String>)>>;
+
+/// Health check endpoint
+///
+/// GET /health
+/// GET /health?deep=true
+///
+/// Without `deep=true`, behaves exactly like [`ready`]. With `deep=true`,
+/// additionally mints a throwaway token (bypassing cache) for a fixed
+/// internal content binding, proving BotGuard can actually mint rather than
+/// just that it reports itself initialized. The deep result is cached for
+/// [`DEEP_HEALTH_CACHE_TTL`] so a probe polling this endpoint doesn't trigger
+/// a mint on every call.
+pub async fn health(State(state): State<AppState>, Query(query): Query<HealthQuery>) -> Response {
+    if !query.deep {
+        return ready(State(state)).await;
+    }
+
+    let cached = {
+        let cache = state.deep_health_cache.lock().await;
+        cache
+            .as_ref()
+            .filter(|(checked_at, _)| checked_at.elapsed() < DEEP_HEALTH_CACHE_TTL)
+            .map(|(_, result)| result.clone())
+    };
+
+    let result = match cached {
+        Some(result) => result,
+        None => {
+            let started_at = std::time::Instant::now();
+            let request = PotRequest::new()
+                .with_content_binding(DEEP_HEALTH_CONTENT_BINDING)
+                .with_bypass_cache(true);
+            let result = state
+                .session_manager
+                .generate_pot_token(&request)
+                .await
+                .map(|_| started_at.elapsed().as_millis() as u64)
+                .map_err(|e| e.category().to_string());
+
+            *state.deep_health_cache.lock().await = Some((started_at, result.clone()));
+            result
+        }
+    };
+
+    match result {
+        Ok(mint_latency_ms) => Json(DeepHealthResponse::new(mint_latency_ms)).into_response(),
+        Err(category) => {
+            tracing::debug!("Deep health check failed: {}", category);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorResponse::with_context(
+                    "BotGuard failed to mint a throwaway health-check token",
+                    category,
+                )),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Build a structured error response for a failed invalidation, matching
+/// the `ErrorResponse` shape `/get_pot` and `/minter_cache` already use
+fn invalidation_error_response(
+    e: &crate::Error,
+    operation: &str,
+    context: &'static str,
+) -> (StatusCode, Json<ErrorResponse>) {
+    tracing::error!("Failed to {}: {}", operation, e);
+    let error_response =
+        ErrorResponse::with_context(format!("Failed to {}: {}", operation, e), context);
+    (StatusCode::INTERNAL_SERVER_ERROR, Json(error_response))
+}
+
 /// Invalidate caches endpoint
 ///
 /// POST /invalidate_caches
 ///
 /// Clears all internal caches.
-pub async fn invalidate_caches(State(state): State<AppState>) -> StatusCode {
+pub async fn invalidate_caches(
+    State(state): State<AppState>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
     tracing::info!("Invalidating all caches");
-    if let Err(e) = state.session_manager.invalidate_caches().await {
-        tracing::error!("Failed to invalidate caches: {}", e);
-        return StatusCode::INTERNAL_SERVER_ERROR;
-    }
-    StatusCode::NO_CONTENT
+    state
+        .session_manager
+        .invalidate_caches()
+        .await
+        .map_err(|e| invalidation_error_response(&e, "invalidate caches", "cache_invalidation"))?;
+    Ok(StatusCode::NO_CONTENT)
 }
 
 /// Invalidate integrity tokens endpoint
@@ -185,26 +752,60 @@ pub async fn invalidate_caches(State(state): State<AppState>) -> StatusCode {
 /// POST /invalidate_it
 ///
 /// Invalidates integrity tokens to force regeneration.
-pub async fn invalidate_it(State(state): State<AppState>) -> StatusCode {
+pub async fn invalidate_it(
+    State(state): State<AppState>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
     tracing::info!("Invalidating integrity tokens");
-    if let Err(e) = state.session_manager.invalidate_integrity_tokens().await {
-        tracing::error!("Failed to invalidate integrity tokens: {}", e);
-        return StatusCode::INTERNAL_SERVER_ERROR;
-    }
-    StatusCode::NO_CONTENT
+    state
+        .session_manager
+        .invalidate_integrity_tokens()
+        .await
+        .map_err(|e| {
+            invalidation_error_response(
+                &e,
+                "invalidate integrity tokens",
+                "integrity_token_invalidation",
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Prune expired cache entries endpoint
+///
+/// POST /cache/prune
+///
+/// Evicts only entries that have already expired from the session-data and
+/// minter caches, reclaiming memory without discarding tokens that are still
+/// fresh. Unlike `/invalidate_caches`, which clears everything unconditionally,
+/// this is safe to call on a schedule.
+pub async fn cache_prune(State(state): State<AppState>) -> Json<CachePruneResponse> {
+    tracing::info!("Pruning expired cache entries");
+    let (session_entries_removed, minter_entries_removed) =
+        state.session_manager.prune_expired_caches().await;
+    Json(CachePruneResponse::new(
+        session_entries_removed,
+        minter_entries_removed,
+    ))
 }
 
 /// Get minter cache keys endpoint
 ///
 /// GET /minter_cache
 ///
-/// Returns the current minter cache keys for debugging.
+/// Returns the current minter cache keys for debugging. Supports conditional
+/// GET: sends an `ETag` derived from the sorted key set, and returns `304 Not
+/// Modified` when the request's `If-None-Match` already matches it.
 pub async fn minter_cache(
     State(state): State<AppState>,
-) -> Result<Json<Vec<String>>, (StatusCode, Json<ErrorResponse>)> {
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     tracing::debug!("Retrieving minter cache keys");
     match state.session_manager.get_minter_cache_keys().await {
-        Ok(cache_keys) => Ok(Json(cache_keys)),
+        Ok(mut cache_keys) => {
+            cache_keys.sort();
+            let etag = weak_etag(&cache_keys);
+            Ok(conditional_json_response(&headers, &etag, cache_keys))
+        }
         Err(e) => {
             tracing::error!("Failed to retrieve minter cache keys: {}", e);
             let error_response = ErrorResponse::with_context(
@@ -216,72 +817,1031 @@ pub async fn minter_cache(
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{config::Settings, session::SessionManager};
-    use std::sync::Arc;
-
-    fn create_test_state() -> AppState {
-        let settings = Settings::default();
-        AppState {
-            session_manager: Arc::new(SessionManager::new(settings.clone())),
-            settings: Arc::new(settings),
-            start_time: std::time::Instant::now(),
+/// List minter cache entries with expiry details endpoint
+///
+/// GET /minter_cache/detail
+///
+/// Returns every minter cache key together with its expiry, remaining
+/// seconds, estimated TTL, and whether it has already expired - a more
+/// detailed sibling of [`minter_cache`] for debugging expiry issues. Left
+/// unauthenticated like [`minter_cache`], since it reveals the same cache
+/// keys plus timing information, not content bindings.
+pub async fn minter_cache_detail(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<MinterCacheDetailEntry>>, (StatusCode, Json<ErrorResponse>)> {
+    tracing::debug!("Retrieving minter cache detail");
+    match state.session_manager.get_minter_cache_detail().await {
+        Ok(mut entries) => {
+            entries.sort_by(|a, b| a.key.cmp(&b.key));
+            Ok(Json(entries))
+        }
+        Err(e) => {
+            tracing::error!("Failed to retrieve minter cache detail: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::with_context(
+                    format!("Failed to get minter cache detail: {}", e),
+                    "cache_retrieval",
+                )),
+            ))
         }
     }
+}
 
-    #[tokio::test]
-    async fn test_ping_handler() {
-        let state = create_test_state();
-        let response = ping(State(state)).await;
-
-        assert!(!response.version.is_empty());
-        assert!(response.server_uptime < 1); // Should be very small for fresh state
+/// List session-data cache entries endpoint
+///
+/// GET /cache/entries
+///
+/// Returns every cached content binding together with its expiry, a more
+/// detailed sibling of [`minter_cache`]. Protected by
+/// [`crate::config::ServerSettings::auth_token`] when configured, since
+/// content bindings can reveal viewed video/session identifiers.
+pub async fn cache_entries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<SessionCacheEntry>>, (StatusCode, Json<ErrorResponse>)> {
+    if !is_authorized(&headers, &state.settings.server.auth_token) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse::with_context(
+                "Missing or invalid authorization token",
+                "cache_entries_auth",
+            )),
+        ));
     }
 
-    #[tokio::test]
-    async fn test_generate_pot_handler() {
-        let state = create_test_state();
-        let request = PotRequest::new().with_content_binding("test_video");
-        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
-
-        let response = generate_pot(State(state), body).await;
-        // Since we changed to IntoResponse, we can't easily test the structure
-        // but at least we can verify it compiles and runs
-        let _ = response.into_response();
+    tracing::debug!("Retrieving session cache entries");
+    match state.session_manager.get_session_cache_entries().await {
+        Ok(entries) => {
+            let mut entries: Vec<SessionCacheEntry> = entries
+                .into_iter()
+                .map(|(content_binding, expires_at)| {
+                    SessionCacheEntry::new(content_binding, expires_at)
+                })
+                .collect();
+            entries.sort_by(|a, b| a.content_binding.cmp(&b.content_binding));
+            Ok(Json(entries))
+        }
+        Err(e) => {
+            tracing::error!("Failed to retrieve session cache entries: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::with_context(
+                    format!("Failed to get cache entries: {}", e),
+                    "cache_retrieval",
+                )),
+            ))
+        }
     }
+}
 
-    #[tokio::test]
-    async fn test_invalidate_caches_handler() {
-        let state = create_test_state();
-        let status = invalidate_caches(State(state)).await;
-        assert_eq!(status, StatusCode::NO_CONTENT);
+/// Delete a single session-data cache entry endpoint
+///
+/// DELETE /cache/entries/{binding}
+///
+/// Removes the cached token for one content binding, leaving every other
+/// entry and the minter cache untouched. Protected the same way
+/// [`cache_entries`] is. Returns `404` if `binding` has no cached entry.
+pub async fn delete_cache_entry(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(binding): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    if !is_authorized(&headers, &state.settings.server.auth_token) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse::with_context(
+                "Missing or invalid authorization token",
+                "cache_entries_auth",
+            )),
+        ));
     }
 
-    #[tokio::test]
-    async fn test_invalidate_it_handler() {
-        let state = create_test_state();
-        let status = invalidate_it(State(state)).await;
-        assert_eq!(status, StatusCode::NO_CONTENT);
+    tracing::info!("Removing session cache entry for {}", binding);
+    if state
+        .session_manager
+        .remove_session_cache_entry(&binding)
+        .await
+    {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::with_context(
+                format!("No cache entry for content binding: {binding}"),
+                "cache_entry_not_found",
+            )),
+        ))
     }
+}
 
-    #[tokio::test]
-    async fn test_minter_cache_handler() {
-        let state = create_test_state();
-        let response = minter_cache(State(state)).await;
-        // Response should be empty initially but valid
-        assert!(response.is_ok());
-        let cache_keys = response.unwrap().0; // Extract Json<Vec<String>>
-        assert!(cache_keys.is_empty());
+/// Token generation success/failure totals endpoint
+///
+/// GET /cache/stats
+///
+/// A small JSON alternative to scraping `pot_token_generations_total` out of
+/// `GET /metrics`, for operators who'd rather poll a status endpoint.
+/// Supports conditional GET the same way [`minter_cache`] does.
+pub async fn cache_stats(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let (success_count, failure_count) = state.session_manager.token_generation_stats();
+    let etag = weak_etag((success_count, failure_count));
+    let response = CacheStatsResponse::new(success_count, failure_count);
+    conditional_json_response(&headers, &etag, response)
+}
+
+/// Build a `200` JSON response carrying an `ETag` header, or a bare `304 Not
+/// Modified` (with the same `ETag`) if `headers` already has a matching
+/// `If-None-Match`.
+fn conditional_json_response<T: serde::Serialize>(
+    headers: &HeaderMap,
+    etag: &str,
+    body: T,
+) -> Response {
+    if headers
+        .get(axum::http::header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        == Some(etag)
+    {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [(axum::http::header::ETAG, etag.to_string())],
+        )
+            .into_response();
     }
 
-    #[test]
-    fn test_format_error_botguard() {
-        let error = crate::Error::BotGuard {
-            code: "500".to_string(),
-            message: "BotGuard initialization failed".to_string(),
-            info: None,
+    (
+        StatusCode::OK,
+        [(axum::http::header::ETAG, etag.to_string())],
+        Json(body),
+    )
+        .into_response()
+}
+
+/// Diagnostics endpoint
+///
+/// GET /diagnostics
+///
+/// Lightweight visibility into the most recent `generate_pot_token` failure,
+/// without trawling logs. Cleared on the next successful generation.
+pub async fn diagnostics(State(state): State<AppState>) -> Json<DiagnosticsResponse> {
+    let last_error = state.session_manager.last_error().await;
+    Json(DiagnosticsResponse::new(last_error))
+}
+
+/// Prometheus metrics endpoint
+///
+/// GET /metrics
+///
+/// Exposes the `botguard_mint_seconds` histogram tracking how long BotGuard
+/// takes to mint a POT token, the `pot_token_generations_total` and
+/// `cache_evictions_total` counters, and the `session_cache_bytes_estimate`
+/// gauge, in Prometheus text exposition format.
+pub async fn metrics(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(axum::http::header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.session_manager.render_metrics(),
+    )
+}
+
+/// Warm up the token cache for a list of content bindings
+///
+/// POST /warmup
+///
+/// Mints and caches tokens for each binding concurrently, bounded to
+/// [`WARMUP_MAX_CONCURRENCY`] in flight at a time so a large binding list
+/// doesn't overwhelm BotGuard. Reuses [`crate::session::SessionManager::generate_pot_token`]
+/// with `bypass_cache: false`, so bindings with an already-fresh cached token
+/// are skipped cheaply rather than re-minted.
+pub async fn warmup(
+    State(state): State<AppState>,
+    Json(request): Json<WarmupRequest>,
+) -> Json<WarmupResponse> {
+    let semaphore = Arc::new(Semaphore::new(WARMUP_MAX_CONCURRENCY));
+    let mut tasks = tokio::task::JoinSet::new();
+
+    for content_binding in request.content_bindings {
+        let session_manager = state.session_manager.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore
+                .acquire_owned()
+                .await
+                .expect("warmup semaphore is never closed");
+            let pot_request = PotRequest::new()
+                .with_content_binding(content_binding)
+                .with_bypass_cache(false);
+            session_manager.generate_pot_token(&pot_request).await
+        });
+    }
+
+    let mut warmed = 0;
+    let mut failed = 0;
+    while let Some(result) = tasks.join_next().await {
+        match result {
+            Ok(Ok(_)) => warmed += 1,
+            Ok(Err(e)) => {
+                tracing::warn!("Failed to warm up POT token: {}", e);
+                failed += 1;
+            }
+            Err(e) => {
+                tracing::error!("Warmup task panicked: {}", e);
+                failed += 1;
+            }
+        }
+    }
+
+    tracing::info!("Warmup complete: warmed={}, failed={}", warmed, failed);
+    Json(WarmupResponse::new(warmed, failed))
+}
+
+/// Check the `Authorization: Bearer <token>` header against the configured
+/// admin auth token, if any. Returns `true` when the request is authorized
+/// (including when no token is configured, i.e. the endpoint is open).
+fn is_authorized(headers: &HeaderMap, expected_token: &Option<String>) -> bool {
+    let Some(expected_token) = expected_token else {
+        return true;
+    };
+
+    let Some(header_value) = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return false;
+    };
+
+    header_value
+        .strip_prefix("Bearer ")
+        .is_some_and(|token| token == expected_token)
+}
+
+/// Force a fresh BotGuard instance, bypassing the normal expiry check
+///
+/// POST /reinitialize
+///
+/// Protected by [`crate::config::ServerSettings::auth_token`] when
+/// configured, via an `Authorization: Bearer <token>` header. Useful when
+/// yt-dlp starts getting rejected tokens and an operator wants a fresh
+/// BotGuard instance without restarting the server.
+pub async fn reinitialize(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<ReinitializeResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if !is_authorized(&headers, &state.settings.server.auth_token) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse::with_context(
+                "Missing or invalid authorization token",
+                "reinitialize_auth",
+            )),
+        ));
+    }
+
+    tracing::info!("Reinitializing BotGuard on operator request");
+    match state.session_manager.reinitialize_botguard().await {
+        Ok((expires_at, lifetime_secs)) => {
+            Ok(Json(ReinitializeResponse::new(expires_at, lifetime_secs)))
+        }
+        Err(e) => {
+            tracing::error!("Failed to reinitialize BotGuard: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::with_context(
+                    format!("Failed to reinitialize BotGuard: {}", e),
+                    "reinitialize",
+                )),
+            ))
+        }
+    }
+}
+
+/// Effective configuration endpoint
+///
+/// GET /config
+///
+/// Returns the server's [`crate::config::Settings`] as configured after
+/// file + env + CLI merging, so an operator can confirm what's actually in
+/// effect without reading startup logs. Protected by
+/// [`crate::config::ServerSettings::auth_token`] when configured, via an
+/// `Authorization: Bearer <token>` header, since the response can reveal
+/// internal topology (proxy hosts, snapshot paths). Secret fields
+/// (`server.auth_token`, proxy credentials embedded in proxy URLs) are
+/// redacted before serialization.
+pub async fn config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Settings>, (StatusCode, Json<ErrorResponse>)> {
+    if !is_authorized(&headers, &state.settings.server.auth_token) {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse::with_context(
+                "Missing or invalid authorization token",
+                "config_auth",
+            )),
+        ));
+    }
+
+    let mut settings = (*state.settings).clone();
+    if settings.server.auth_token.is_some() {
+        settings.server.auth_token = Some("***redacted***".to_string());
+    }
+    if settings.server.response_signing_key.is_some() {
+        settings.server.response_signing_key = Some("***redacted***".to_string());
+    }
+    settings.network.https_proxy = settings
+        .network
+        .https_proxy
+        .as_deref()
+        .map(redact_proxy_credentials);
+    settings.network.http_proxy = settings
+        .network
+        .http_proxy
+        .as_deref()
+        .map(redact_proxy_credentials);
+    settings.network.all_proxy = settings
+        .network
+        .all_proxy
+        .as_deref()
+        .map(redact_proxy_credentials);
+    for proxy in &mut settings.network.proxy_pool {
+        *proxy = redact_proxy_credentials(proxy);
+    }
+
+    Ok(Json(settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Settings, session::SessionManager};
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    fn create_test_state() -> AppState {
+        let settings = Settings::default();
+        AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ping_handler() {
+        let state = create_test_state();
+        let response = ping(State(state)).await;
+
+        assert!(!response.version.is_empty());
+        assert!(response.server_uptime < 1); // Should be very small for fresh state
+    }
+
+    #[tokio::test]
+    async fn test_version_info_handler() {
+        let response = version_info().await;
+
+        assert_eq!(response.version, version::get_version());
+        assert_eq!(response.git_sha, version::get_git_sha());
+        assert_eq!(response.build_timestamp, version::get_build_timestamp());
+        assert_eq!(
+            response.rustypipe_botguard_version,
+            version::get_rustypipe_botguard_version()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_openapi_spec_handler_lists_get_pot_path() {
+        let response = openapi_spec().await;
+
+        assert_eq!(response.0["openapi"], "3.0.3");
+        assert!(response.0["paths"]["/get_pot"].is_object());
+        assert!(response.0["components"]["schemas"]["PotResponse"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_ready_handler_not_ready() {
+        let state = create_test_state();
+        let response = ready(State(state)).await.into_response();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_ready_handler_ready() {
+        let state = create_test_state();
+        state
+            .session_manager
+            .initialize_botguard()
+            .await
+            .unwrap();
+
+        let response = ready(State(state)).await.into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_health_handler_without_deep_matches_ready() {
+        let state = create_test_state();
+
+        let response = health(State(state.clone()), Query(HealthQuery { deep: false }))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        state
+            .session_manager
+            .initialize_botguard()
+            .await
+            .unwrap();
+
+        let response = health(State(state), Query(HealthQuery { deep: false }))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_health_handler_deep_mints_and_reports_latency() {
+        let state = create_test_state();
+
+        let response = health(State(state), Query(HealthQuery { deep: true }))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: DeepHealthResponse = serde_json::from_slice(&body).unwrap();
+        // Minting and the overhead of the Tokio task take some non-zero time,
+        // but should be well under the test's patience; we only care that a
+        // real latency was measured, not any specific bound.
+        let _ = parsed.mint_latency_ms;
+    }
+
+    #[tokio::test]
+    async fn test_health_handler_deep_caches_result_briefly() {
+        let state = create_test_state();
+
+        let first = health(State(state.clone()), Query(HealthQuery { deep: true })).await;
+        assert_eq!(first.into_response().status(), StatusCode::OK);
+        let (success_after_first, _) = state.session_manager.token_generation_stats();
+
+        let second = health(State(state.clone()), Query(HealthQuery { deep: true })).await;
+        assert_eq!(second.into_response().status(), StatusCode::OK);
+        let (success_after_second, _) = state.session_manager.token_generation_stats();
+
+        assert_eq!(
+            success_after_first, success_after_second,
+            "a cached deep health result must not trigger another mint"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_handler() {
+        let state = create_test_state();
+        let request = PotRequest::new().with_content_binding("test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
+        // Since we changed to IntoResponse, we can't easily test the structure
+        // but at least we can verify it compiles and runs
+        let _ = response.into_response();
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_signs_response_body_when_signing_key_configured() {
+        let mut settings = Settings::default();
+        settings.server.response_signing_key = Some("test-signing-key".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+        let request = PotRequest::new().with_content_binding("test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let signature = response
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .expect("signature header should be set")
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(crate::utils::signature::verify_response_signature(
+            "test-signing-key",
+            &body,
+            &signature
+        ));
+
+        let mut altered = body.to_vec();
+        altered.push(b' ');
+        assert!(!crate::utils::signature::verify_response_signature(
+            "test-signing-key",
+            &altered,
+            &signature
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_omits_signature_header_when_key_unset() {
+        let state = create_test_state();
+        let request = PotRequest::new().with_content_binding("test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body)
+            .await
+            .into_response();
+
+        assert!(response.headers().get(SIGNATURE_HEADER).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_cold_start_yields_cold_start_typed_response() {
+        let state = create_test_state();
+        let request = PotRequest::new()
+            .with_content_binding("test_visitor_data")
+            .with_cold_start(true);
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(parsed["tokenType"], "cold_start");
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_replays_cached_response_for_same_idempotency_key() {
+        let state = create_test_state();
+        let request = PotRequest::new().with_content_binding("test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Idempotency-Key", "retry-123".parse().unwrap());
+
+        let first = generate_pot(State(state.clone()), headers.clone(), body.clone())
+            .await
+            .into_response();
+        assert_eq!(first.status(), StatusCode::OK);
+        let first_body = axum::body::to_bytes(first.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let first_response: PotResponse = serde_json::from_slice(&first_body).unwrap();
+
+        let (success_after_first, _) = state.session_manager.token_generation_stats();
+
+        let second = generate_pot(State(state.clone()), headers, body)
+            .await
+            .into_response();
+        assert_eq!(second.status(), StatusCode::OK);
+        let second_body = axum::body::to_bytes(second.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let second_response: PotResponse = serde_json::from_slice(&second_body).unwrap();
+
+        assert_eq!(first_response, second_response);
+
+        let (success_after_second, _) = state.session_manager.token_generation_stats();
+        assert_eq!(
+            success_after_first, success_after_second,
+            "a replayed idempotency key must not trigger another mint"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_sets_cache_control_from_token_lifetime() {
+        let state = create_test_state();
+        let request = PotRequest::new().with_content_binding("test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let cache_control = response
+            .headers()
+            .get(axum::http::header::CACHE_CONTROL)
+            .and_then(|v| v.to_str().ok())
+            .expect("Cache-Control header should be set");
+        assert!(cache_control.starts_with("private, max-age="));
+        assert!(
+            response
+                .headers()
+                .contains_key(axum::http::header::EXPIRES)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_sets_no_store_when_bypassing_cache() {
+        let state = create_test_state();
+        let request = PotRequest::new()
+            .with_content_binding("test_video")
+            .with_bypass_cache(true);
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        assert_eq!(
+            response
+                .headers()
+                .get(axum::http::header::CACHE_CONTROL)
+                .and_then(|v| v.to_str().ok()),
+            Some("no-store")
+        );
+        assert!(
+            !response
+                .headers()
+                .contains_key(axum::http::header::EXPIRES)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_accepts_unknown_fields_leniently_by_default() {
+        let state = create_test_state();
+        let body = axum::body::Bytes::from(
+            serde_json::to_vec(&serde_json::json!({
+                "content_binding": "test_video",
+                "some_future_yt_dlp_field": "unexpected value",
+            }))
+            .unwrap(),
+        );
+
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_rejects_unknown_fields_in_strict_mode() {
+        let mut settings = Settings::default();
+        settings.server.reject_unknown_fields = true;
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+        let body = axum::body::Bytes::from(
+            serde_json::to_vec(&serde_json::json!({
+                "content_binding": "test_video",
+                "some_future_yt_dlp_field": "unexpected value",
+            }))
+            .unwrap(),
+        );
+
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_allows_content_binding_matching_allowlist() {
+        let settings = Settings::default();
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: Some(Arc::new(regex::Regex::new("^[a-z_]+$").unwrap())),
+        };
+        let request = PotRequest::new().with_content_binding("allowed_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_rejects_content_binding_not_matching_allowlist() {
+        let settings = Settings::default();
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: Some(Arc::new(regex::Regex::new("^[a-z_]+$").unwrap())),
+        };
+        let request = PotRequest::new().with_content_binding("not-allowed-123");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_handler_reports_mint_after_generate_pot() {
+        let state = create_test_state();
+        let request = PotRequest::new().with_content_binding("metrics_test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let _ = generate_pot(State(state.clone()), HeaderMap::new(), body).await;
+
+        let response = metrics(State(state)).await.into_response();
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body_text = String::from_utf8(body_bytes.to_vec()).unwrap();
+
+        assert!(body_text.contains("botguard_mint_seconds_count 1"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_pot_handler_times_out() {
+        // A zero-second deadline elapses before generation can ever complete,
+        // standing in for a session manager whose generation never finishes.
+        let mut settings = Settings::default();
+        settings.token.pot_generation_timeout = 0;
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+        let request = PotRequest::new().with_content_binding("test_video");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_caches_handler() {
+        let state = create_test_state();
+        let status = invalidate_caches(State(state)).await.unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_it_handler() {
+        let state = create_test_state();
+        let status = invalidate_it(State(state)).await.unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_cache_prune_handler_removes_expired_entry_but_keeps_fresh_one() {
+        let state = create_test_state();
+
+        let caches: crate::session::manager::SessionDataCaches = dashmap::DashMap::new();
+        caches.insert(
+            "expired_video".to_string(),
+            std::sync::Arc::new(crate::types::SessionData::new(
+                "expired_token",
+                "expired_video",
+                chrono::Utc::now() - chrono::Duration::hours(1),
+            )),
+        );
+        caches.insert(
+            "fresh_video".to_string(),
+            std::sync::Arc::new(crate::types::SessionData::new(
+                "fresh_token",
+                "fresh_video",
+                chrono::Utc::now() + chrono::Duration::hours(1),
+            )),
+        );
+        state.session_manager.set_session_data_caches(caches).await;
+
+        let Json(response) = cache_prune(State(state.clone())).await;
+        assert_eq!(response.session_entries_removed, 1);
+
+        let remaining = state.session_manager.get_session_data_caches(false).await;
+        assert!(!remaining.contains_key("expired_video"));
+        assert!(remaining.contains_key("fresh_video"));
+    }
+
+    #[test]
+    fn test_invalidation_error_response_yields_structured_error_with_context() {
+        let (status, Json(body)) = invalidation_error_response(
+            &crate::Error::cache("clear", "forced failure for test"),
+            "invalidate caches",
+            "cache_invalidation",
+        );
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body.context.as_deref(), Some("cache_invalidation"));
+        assert!(body.error.contains("forced failure for test"));
+    }
+
+    #[tokio::test]
+    async fn test_minter_cache_handler() {
+        let state = create_test_state();
+        let response = minter_cache(State(state), HeaderMap::new())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let cache_keys: Vec<String> = serde_json::from_slice(&body).unwrap();
+        assert!(cache_keys.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_minter_cache_detail_handler_includes_expiry_for_populated_minter() {
+        let state = create_test_state();
+
+        let cache: crate::session::manager::MinterCache = dashmap::DashMap::new();
+        let expires_at = Utc::now() + chrono::Duration::hours(1);
+        cache.insert(
+            "test_minter_key".to_string(),
+            crate::types::TokenMinterEntry::new(expires_at, "test_it", 3600, 600, None),
+        );
+        state.session_manager.set_minter_cache(cache).await;
+
+        let Json(entries) = minter_cache_detail(State(state)).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "test_minter_key");
+        assert_eq!(entries[0].expires_at, expires_at);
+        assert_eq!(entries[0].estimated_ttl_secs, 3600);
+        assert!(!entries[0].is_expired);
+    }
+
+    #[tokio::test]
+    async fn test_minter_cache_repeat_request_with_matching_etag_returns_304() {
+        let state = create_test_state();
+
+        let first = minter_cache(State(state.clone()), HeaderMap::new())
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(axum::http::header::ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::header::IF_NONE_MATCH,
+            etag.parse().unwrap(),
+        );
+        let second = minter_cache(State(state), headers).await.unwrap();
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn test_minter_cache_etag_changes_after_mutation() {
+        let state = create_test_state();
+
+        let first = minter_cache(State(state.clone()), HeaderMap::new())
+            .await
+            .unwrap();
+        let etag_before = first
+            .headers()
+            .get(axum::http::header::ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let request = PotRequest::new().with_content_binding("etag_mutation_test");
+        state
+            .session_manager
+            .generate_pot_token(&request)
+            .await
+            .unwrap();
+
+        let second = minter_cache(State(state), HeaderMap::new())
+            .await
+            .unwrap();
+        let etag_after = second
+            .headers()
+            .get(axum::http::header::ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        assert_ne!(
+            etag_before, etag_after,
+            "minting a new token should change the minter cache ETag"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_stats_repeat_request_with_matching_etag_returns_304() {
+        let state = create_test_state();
+
+        let first = cache_stats(State(state.clone()), HeaderMap::new()).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(axum::http::header::ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::header::IF_NONE_MATCH,
+            etag.parse().unwrap(),
+        );
+        let second = cache_stats(State(state), headers).await;
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_handler_populates_session_cache() {
+        let state = create_test_state();
+        let request = WarmupRequest {
+            content_bindings: vec!["warmup_video_1".to_string(), "warmup_video_2".to_string()],
+        };
+
+        let response = warmup(State(state.clone()), Json(request)).await;
+
+        assert_eq!(response.warmed, 2);
+        assert_eq!(response.failed, 0);
+
+        let caches = state.session_manager.get_session_data_caches(false).await;
+        assert!(caches.get("warmup_video_1").is_some());
+        assert!(caches.get("warmup_video_2").is_some());
+    }
+
+    #[test]
+    fn test_status_for_error_validation() {
+        let error = crate::Error::validation("content_binding", "too long");
+        assert_eq!(status_for_error(&error), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn test_status_for_error_missing_video_id() {
+        let error = crate::Error::missing_video_id();
+        assert_eq!(status_for_error(&error), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn test_status_for_error_rate_limit() {
+        let error = crate::Error::rate_limit("rate limited", Some(30));
+        assert_eq!(status_for_error(&error), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[test]
+    fn test_status_for_error_timeout() {
+        let error = crate::Error::timeout("generate_pot_token", 30);
+        assert_eq!(status_for_error(&error), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[test]
+    fn test_status_for_error_proxy() {
+        let error = crate::Error::proxy("http://proxy:8080", "invalid proxy");
+        assert_eq!(status_for_error(&error), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_status_for_error_config() {
+        let error = crate::Error::config("network.proxy", "invalid config");
+        assert_eq!(status_for_error(&error), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_status_for_error_falls_back_to_internal_server_error() {
+        let error = crate::Error::internal("unexpected");
+        assert_eq!(status_for_error(&error), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_format_error_botguard() {
+        let error = crate::Error::BotGuard {
+            code: "500".to_string(),
+            message: "BotGuard initialization failed".to_string(),
+            info: None,
         };
         let formatted = format_error(&error);
         assert!(formatted.contains("BGError(500)"));
@@ -439,12 +1999,84 @@ mod tests {
         let request = PotRequest::new(); // No content binding set
         let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
 
-        let response = generate_pot(State(state), body).await;
+        let response = generate_pot(State(state), HeaderMap::new(), body).await;
         // Since we changed to IntoResponse, we can't easily test the structure
         // but at least we can verify it compiles and runs
         let _ = response.into_response();
     }
 
+    #[tokio::test]
+    async fn test_generate_pot_rejects_invalid_proxy() {
+        let state = create_test_state();
+        let request = PotRequest::new()
+            .with_content_binding("test_video")
+            .with_proxy("not a url");
+        let body = axum::body::Bytes::from(serde_json::to_vec(&request).unwrap());
+
+        let response = generate_pot(State(state), HeaderMap::new(), body)
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn test_token_generation_error_response_includes_botguard_code_and_info() {
+        let error = crate::Error::botguard_with_info(
+            "403",
+            "forbidden",
+            serde_json::json!({"reason": "snapshot_expired"}),
+        );
+
+        let (status, Json(body)) = token_generation_error_response(&error);
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        let details = body.details.expect("BotGuard error should carry details");
+        assert_eq!(details["code"], "403");
+        assert_eq!(details["info"]["reason"], "snapshot_expired");
+    }
+
+    #[test]
+    fn test_token_generation_error_response_omits_details_for_non_botguard_errors() {
+        let error = crate::Error::timeout("generate_pot_token", 30);
+
+        let (_, Json(body)) = token_generation_error_response(&error);
+
+        assert!(body.details.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_reports_and_clears_last_error() {
+        let state = create_test_state();
+
+        let empty = diagnostics(State(state.clone())).await;
+        assert!(empty.last_error.is_none());
+
+        // Fails inside SessionManager::generate_pot_token_inner (invalid proxy),
+        // distinct from the handler's own upfront `request.validate()` check.
+        let failing_request = PotRequest::new().with_proxy("not-a-valid-url");
+        assert!(
+            state
+                .session_manager
+                .generate_pot_token(&failing_request)
+                .await
+                .is_err()
+        );
+
+        let after_failure = diagnostics(State(state.clone())).await;
+        assert!(after_failure.last_error.is_some());
+        assert!(after_failure.last_error_at.is_some());
+
+        let success_request = PotRequest::new().with_content_binding("diagnostics_test_video");
+        state
+            .session_manager
+            .generate_pot_token(&success_request)
+            .await
+            .unwrap();
+
+        let after_success = diagnostics(State(state)).await;
+        assert!(after_success.last_error.is_none());
+    }
+
     #[tokio::test]
     async fn test_ping_handler_timing() {
         use std::time::Duration;
@@ -475,7 +2107,12 @@ mod deprecated_field_tests {
     use tower::ServiceExt;
 
     fn create_test_app() -> axum::Router {
-        let settings = Settings::default();
+        create_test_app_with_policy(DeprecatedFieldPolicy::default())
+    }
+
+    fn create_test_app_with_policy(policy: DeprecatedFieldPolicy) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.server.deprecated_field_policy = policy;
         let session_manager =
             std::sync::Arc::new(crate::session::SessionManager::new(settings.clone()));
 
@@ -483,55 +2120,56 @@ mod deprecated_field_tests {
             session_manager,
             settings: std::sync::Arc::new(settings),
             start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
         };
 
         axum::Router::new()
             .route("/get_pot", axum::routing::post(generate_pot))
-            .layer(axum::middleware::from_fn(
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
                 validate_deprecated_fields_middleware,
             ))
             .with_state(state)
     }
 
     #[tokio::test]
-    async fn test_deprecated_data_sync_id_field() {
-        // Arrange
+    async fn test_data_sync_id_field_mints_session_bound_token() {
+        // data_sync_id is a first-class field (not deprecated), used as the
+        // content binding for a session-bound mint when no content_binding
+        // is supplied.
         let app = create_test_app();
 
-        let deprecated_request = json!({
-            "data_sync_id": "deprecated_value",
-            "content_binding": "video_id"
+        let request_body = json!({
+            "data_sync_id": "sync_id_value"
         });
 
         let request = Request::builder()
             .method("POST")
             .uri("/get_pot")
             .header("content-type", "application/json")
-            .body(Body::from(deprecated_request.to_string()))
+            .body(Body::from(request_body.to_string()))
             .unwrap();
 
         // Act
         let response = app.oneshot(request).await.unwrap();
 
         // Assert
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::OK);
 
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let json_response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let pot_response: crate::types::PotResponse = serde_json::from_slice(&body).unwrap();
 
-        assert_eq!(
-            json_response["error"],
-            "data_sync_id is deprecated, use content_binding instead"
-        );
-        assert_eq!(json_response["context"], "deprecated_field_validation");
+        assert_eq!(pot_response.content_binding, "sync_id_value");
     }
 
     #[tokio::test]
-    async fn test_deprecated_visitor_data_field() {
+    async fn test_deprecated_visitor_data_field_rejected() {
         // Arrange
-        let app = create_test_app();
+        let app = create_test_app_with_policy(DeprecatedFieldPolicy::Reject);
 
         let deprecated_request = json!({
             "visitor_data": "deprecated_visitor",
@@ -564,12 +2202,69 @@ mod deprecated_field_tests {
     }
 
     #[tokio::test]
-    async fn test_both_deprecated_fields() {
-        // Arrange
-        let app = create_test_app();
+    async fn test_deprecated_visitor_data_field_warned() {
+        // Default policy: process the request normally and add a Warning header.
+        let app = create_test_app_with_policy(DeprecatedFieldPolicy::Warn);
+
+        let deprecated_request = json!({
+            "visitor_data": "deprecated_visitor",
+            "content_binding": "video_id"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/get_pot")
+            .header("content-type", "application/json")
+            .body(Body::from(deprecated_request.to_string()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let warning = response
+            .headers()
+            .get(axum::http::header::WARNING)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        assert!(warning.contains("visitor_data is deprecated"));
+    }
+
+    #[tokio::test]
+    async fn test_deprecated_visitor_data_field_ignored() {
+        // Process the request normally with no warning at all.
+        let app = create_test_app_with_policy(DeprecatedFieldPolicy::Ignore);
+
+        let deprecated_request = json!({
+            "visitor_data": "deprecated_visitor",
+            "content_binding": "video_id"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/get_pot")
+            .header("content-type", "application/json")
+            .body(Body::from(deprecated_request.to_string()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            response
+                .headers()
+                .get(axum::http::header::WARNING)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_data_sync_id_alongside_deprecated_visitor_data() {
+        // data_sync_id is accepted, but visitor_data alongside it still
+        // triggers the deprecated-field rejection under the reject policy.
+        let app = create_test_app_with_policy(DeprecatedFieldPolicy::Reject);
 
         let deprecated_request = json!({
-            "data_sync_id": "deprecated_data",
+            "data_sync_id": "sync_id_value",
             "visitor_data": "deprecated_visitor",
             "content_binding": "video_id"
         });
@@ -592,10 +2287,9 @@ mod deprecated_field_tests {
             .unwrap();
         let json_response: serde_json::Value = serde_json::from_slice(&body).unwrap();
 
-        // Should return error for data_sync_id (first check)
         assert_eq!(
             json_response["error"],
-            "data_sync_id is deprecated, use content_binding instead"
+            "visitor_data is deprecated, use content_binding instead"
         );
         assert_eq!(json_response["context"], "deprecated_field_validation");
     }
@@ -672,3 +2366,537 @@ mod deprecated_field_tests {
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 }
+
+// Additional tests for the access logging middleware
+#[cfg(test)]
+mod access_log_tests {
+    use super::*;
+    use crate::config::Settings;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[derive(Clone, Default)]
+    struct CapturingWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for CapturingWriter {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn create_test_app_with_logging(log_requests: bool) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.logging.log_requests = log_requests;
+        let session_manager =
+            std::sync::Arc::new(crate::session::SessionManager::new(settings.clone()));
+
+        let state = AppState {
+            session_manager,
+            settings: std::sync::Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        axum::Router::new()
+            .route("/ping", axum::routing::get(ping))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                access_log_middleware,
+            ))
+            .with_state(state)
+    }
+
+    fn create_test_app_with_trusted_proxies(trusted_proxies: Vec<String>) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.logging.log_requests = true;
+        settings.server.trusted_proxies = trusted_proxies;
+        let session_manager =
+            std::sync::Arc::new(crate::session::SessionManager::new(settings.clone()));
+
+        let state = AppState {
+            session_manager,
+            settings: std::sync::Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        axum::Router::new()
+            .route("/ping", axum::routing::get(ping))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                access_log_middleware,
+            ))
+            .with_state(state)
+    }
+
+    #[tokio::test]
+    async fn test_access_log_middleware_logs_method_path_and_status() {
+        let buffer = CapturingWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .finish();
+
+        let app = create_test_app_with_logging(true);
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .body(Body::empty())
+            .unwrap();
+
+        let guard = tracing::subscriber::set_default(subscriber);
+        let response = app.oneshot(request).await.unwrap();
+        drop(guard);
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let captured = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(captured.contains("/ping"));
+        assert!(captured.contains("200"));
+    }
+
+    #[tokio::test]
+    async fn test_access_log_middleware_disabled_by_setting() {
+        let buffer = CapturingWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .finish();
+
+        let app = create_test_app_with_logging(false);
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .body(Body::empty())
+            .unwrap();
+
+        let guard = tracing::subscriber::set_default(subscriber);
+        let response = app.oneshot(request).await.unwrap();
+        drop(guard);
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let captured = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(captured.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_access_log_middleware_logs_connect_info_when_present() {
+        use axum::extract::{ConnectInfo, Extension};
+
+        let buffer = CapturingWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .finish();
+
+        let app = create_test_app_with_logging(true).layer(Extension(ConnectInfo(
+            "203.0.113.7:54321".parse::<std::net::SocketAddr>().unwrap(),
+        )));
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .body(Body::empty())
+            .unwrap();
+
+        let guard = tracing::subscriber::set_default(subscriber);
+        let response = app.oneshot(request).await.unwrap();
+        drop(guard);
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let captured = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(captured.contains("203.0.113.7:54321"));
+    }
+
+    #[tokio::test]
+    async fn test_access_log_middleware_honors_forwarded_for_from_trusted_proxy() {
+        use axum::extract::{ConnectInfo, Extension};
+
+        let buffer = CapturingWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .finish();
+
+        let app =
+            create_test_app_with_trusted_proxies(vec!["10.0.0.0/8".to_string()]).layer(Extension(
+                ConnectInfo("10.0.0.5:54321".parse::<std::net::SocketAddr>().unwrap()),
+            ));
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header("x-forwarded-for", "203.0.113.9")
+            .body(Body::empty())
+            .unwrap();
+
+        let guard = tracing::subscriber::set_default(subscriber);
+        let response = app.oneshot(request).await.unwrap();
+        drop(guard);
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let captured = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(captured.contains("203.0.113.9"));
+        assert!(!captured.contains("10.0.0.5"));
+    }
+
+    #[tokio::test]
+    async fn test_access_log_middleware_ignores_forwarded_for_from_untrusted_proxy() {
+        use axum::extract::{ConnectInfo, Extension};
+
+        let buffer = CapturingWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buffer.clone())
+            .with_ansi(false)
+            .finish();
+
+        let app = create_test_app_with_trusted_proxies(vec!["10.0.0.0/8".to_string()]).layer(
+            Extension(ConnectInfo(
+                "198.51.100.1:54321"
+                    .parse::<std::net::SocketAddr>()
+                    .unwrap(),
+            )),
+        );
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .header("x-forwarded-for", "203.0.113.9")
+            .body(Body::empty())
+            .unwrap();
+
+        let guard = tracing::subscriber::set_default(subscriber);
+        let response = app.oneshot(request).await.unwrap();
+        drop(guard);
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let captured = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(captured.contains("198.51.100.1"));
+        assert!(!captured.contains("203.0.113.9"));
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_handler_succeeds_and_returns_updated_expiry() {
+        let state = create_test_state();
+        state.session_manager.initialize_botguard().await.unwrap();
+
+        let response = reinitialize(State(state.clone()), HeaderMap::new())
+            .await
+            .unwrap();
+
+        assert!(response.expires_at > Utc::now());
+        assert!(response.lifetime_secs > 0);
+        assert!(state.session_manager.is_ready().await);
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_handler_rejects_missing_token_when_configured() {
+        let mut settings = Settings::default();
+        settings.server.auth_token = Some("super-secret".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        let result = reinitialize(State(state), HeaderMap::new()).await;
+
+        let (status, _) = result.unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_reinitialize_handler_accepts_matching_token() {
+        let mut settings = Settings::default();
+        settings.server.auth_token = Some("super-secret".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::header::AUTHORIZATION,
+            "Bearer super-secret".parse().unwrap(),
+        );
+
+        let result = reinitialize(State(state), headers).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_config_handler_returns_configured_port() {
+        let state = create_test_state();
+        let response = config(State(state.clone()), HeaderMap::new())
+            .await
+            .unwrap();
+        assert_eq!(response.0.server.port, state.settings.server.port);
+    }
+
+    #[tokio::test]
+    async fn test_config_handler_redacts_auth_token() {
+        let mut settings = Settings::default();
+        settings.server.auth_token = Some("super-secret".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::header::AUTHORIZATION,
+            "Bearer super-secret".parse().unwrap(),
+        );
+
+        let response = config(State(state), headers).await.unwrap();
+        assert_ne!(
+            response.0.server.auth_token.as_deref(),
+            Some("super-secret")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_config_handler_rejects_missing_token_when_configured() {
+        let mut settings = Settings::default();
+        settings.server.auth_token = Some("super-secret".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        let result = config(State(state), HeaderMap::new()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cache_entries_handler_lists_content_binding_and_expiry() {
+        let state = create_test_state();
+
+        let caches: crate::session::manager::SessionDataCaches = dashmap::DashMap::new();
+        let expires_at = Utc::now() + chrono::Duration::hours(1);
+        caches.insert(
+            "test_video".to_string(),
+            Arc::new(crate::types::SessionData::new(
+                "test_token",
+                "test_video",
+                expires_at,
+            )),
+        );
+        state.session_manager.set_session_data_caches(caches).await;
+
+        let Json(entries) = cache_entries(State(state), HeaderMap::new()).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].content_binding, "test_video");
+        assert_eq!(entries[0].expires_at, expires_at);
+    }
+
+    #[tokio::test]
+    async fn test_cache_entries_handler_rejects_missing_token_when_configured() {
+        let mut settings = Settings::default();
+        settings.server.auth_token = Some("super-secret".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        let result = cache_entries(State(state), HeaderMap::new()).await;
+        let (status, _) = result.unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_delete_cache_entry_handler_removes_known_entry() {
+        let state = create_test_state();
+
+        let caches: crate::session::manager::SessionDataCaches = dashmap::DashMap::new();
+        caches.insert(
+            "test_video".to_string(),
+            Arc::new(crate::types::SessionData::new(
+                "test_token",
+                "test_video",
+                Utc::now() + chrono::Duration::hours(1),
+            )),
+        );
+        state.session_manager.set_session_data_caches(caches).await;
+
+        let status = delete_cache_entry(
+            State(state.clone()),
+            HeaderMap::new(),
+            Path("test_video".to_string()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let remaining = state.session_manager.get_session_data_caches(false).await;
+        assert!(!remaining.contains_key("test_video"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_cache_entry_handler_returns_404_for_unknown_binding() {
+        let state = create_test_state();
+
+        let result = delete_cache_entry(
+            State(state),
+            HeaderMap::new(),
+            Path("missing_video".to_string()),
+        )
+        .await;
+
+        let (status, _) = result.unwrap_err();
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_delete_cache_entry_handler_rejects_missing_token_when_configured() {
+        let mut settings = Settings::default();
+        settings.server.auth_token = Some("super-secret".to_string());
+        let state = AppState {
+            session_manager: Arc::new(SessionManager::new(settings.clone())),
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        let result = delete_cache_entry(
+            State(state),
+            HeaderMap::new(),
+            Path("test_video".to_string()),
+        )
+        .await;
+        let (status, _) = result.unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
+
+// Additional tests for the error pretty-printing middleware
+#[cfg(test)]
+mod pretty_print_errors_tests {
+    use super::*;
+    use crate::config::Settings;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn create_test_app_with_pretty_errors(pretty_errors: bool) -> axum::Router {
+        let mut settings = Settings::default();
+        settings.server.pretty_errors = pretty_errors;
+        let session_manager =
+            std::sync::Arc::new(crate::session::SessionManager::new(settings.clone()));
+
+        let state = AppState {
+            session_manager,
+            settings: Arc::new(settings),
+            start_time: std::time::Instant::now(),
+            deep_health_cache: Arc::new(tokio::sync::Mutex::new(None)),
+            idempotency_cache: Arc::new(dashmap::DashMap::new()),
+            content_binding_allow_regex: None,
+        };
+
+        axum::Router::new()
+            .route("/ping", axum::routing::get(ping))
+            .route("/get_pot", axum::routing::post(generate_pot))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                pretty_print_errors_middleware,
+            ))
+            .with_state(state)
+    }
+
+    async fn error_body(pretty_errors: bool) -> String {
+        let app = create_test_app_with_pretty_errors(pretty_errors);
+        let request = Request::builder()
+            .method("POST")
+            .uri("/get_pot")
+            .header("content-type", "application/json")
+            .body(Body::from("not json"))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pretty_errors_disabled_by_default_is_single_line() {
+        let body = error_body(false).await;
+        assert_eq!(body.lines().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pretty_errors_enabled_produces_indented_output() {
+        let body = error_body(true).await;
+        assert!(body.lines().count() > 1);
+        assert!(body.lines().any(|line| line.starts_with("  ")));
+    }
+
+    #[tokio::test]
+    async fn test_pretty_errors_enabled_leaves_success_responses_untouched() {
+        let app = create_test_app_with_pretty_errors(true);
+        let request = Request::builder()
+            .method("GET")
+            .uri("/ping")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(body.lines().count(), 1);
+    }
+}
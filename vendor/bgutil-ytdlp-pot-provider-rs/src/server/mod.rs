@@ -3,6 +3,9 @@
 //! This module contains the HTTP server implementation using Axum framework.
 
 pub mod app;
+pub mod client_ip;
 pub mod handlers;
+pub mod proxy_listener;
+pub mod proxy_protocol;
 
 pub use app::create_app;
@@ -0,0 +1,176 @@
+//! A [`TcpListener`] wrapper that understands PROXY protocol v1
+//!
+//! Lets the server sit behind a TCP proxy (HAProxy, AWS NLB, etc.) that's
+//! configured to send a PROXY protocol v1 header ahead of each connection,
+//! while still exposing the real client address to handlers and access
+//! logs via [`axum::extract::ConnectInfo`] instead of the proxy's own
+//! socket address.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::extract::connect_info::Connected;
+use axum::serve::{IncomingStream, Listener};
+use tokio::io::AsyncReadExt;
+use tokio::net::{TcpListener, TcpStream};
+
+use super::proxy_protocol::{self, MAX_V1_HEADER_LEN};
+
+/// How long to wait for a PROXY v1 header to finish arriving before giving
+/// up and falling back to the raw TCP peer address.
+const PROXY_HEADER_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Wraps a [`TcpListener`], peeking every accepted connection for a leading
+/// PROXY protocol v1 header and stripping it off when present.
+///
+/// Connections that don't start with a recognizable header are passed
+/// through untouched and keep their raw peer address - this only reports a
+/// different address when a header was actually found, so it's safe to
+/// enable against a proxy that doesn't send one for every connection type
+/// (e.g. a health checker hitting the port directly).
+pub struct ProxyProtocolListener {
+    inner: TcpListener,
+}
+
+impl ProxyProtocolListener {
+    pub fn new(inner: TcpListener) -> Self {
+        Self { inner }
+    }
+}
+
+impl Listener for ProxyProtocolListener {
+    type Io = TcpStream;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            let (mut stream, peer_addr) = match self.inner.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    tracing::error!("accept error: {e}");
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            let addr = match peek_proxy_header(&stream).await {
+                Some(header) => match stream.read_exact(&mut vec![0u8; header.consumed]).await {
+                    Ok(_) => header.client_addr,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to strip PROXY protocol header from {peer_addr}: {e}"
+                        );
+                        peer_addr
+                    }
+                },
+                None => peer_addr,
+            };
+
+            return (stream, addr);
+        }
+    }
+
+    fn local_addr(&self) -> std::io::Result<Self::Addr> {
+        self.inner.local_addr()
+    }
+}
+
+/// Peeks at the start of `stream` without consuming anything, waiting up to
+/// [`PROXY_HEADER_TIMEOUT`] for a complete PROXY v1 header to arrive.
+async fn peek_proxy_header(stream: &TcpStream) -> Option<proxy_protocol::ProxyHeader> {
+    let mut buf = [0u8; MAX_V1_HEADER_LEN];
+    let deadline = tokio::time::Instant::now() + PROXY_HEADER_TIMEOUT;
+    loop {
+        let n = match tokio::time::timeout_at(deadline, stream.peek(&mut buf)).await {
+            Ok(Ok(n)) => n,
+            _ => return None,
+        };
+        if let Some(header) = proxy_protocol::parse_v1(&buf[..n]) {
+            return Some(header);
+        }
+        if n == buf.len() {
+            return None;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+/// Exposes the PROXY-protocol-resolved client address (or the raw peer
+/// address, when no header was present) through [`axum::extract::ConnectInfo`].
+impl Connected<IncomingStream<'_, ProxyProtocolListener>> for SocketAddr {
+    fn connect_info(stream: IncomingStream<'_, ProxyProtocolListener>) -> Self {
+        *stream.remote_addr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, extract::ConnectInfo, routing::get};
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpStream as ClientStream;
+
+    #[tokio::test]
+    async fn test_connect_info_sees_proxy_header_client_address() {
+        async fn handler(ConnectInfo(addr): ConnectInfo<SocketAddr>) -> String {
+            addr.to_string()
+        }
+
+        let listener = ProxyProtocolListener::new(TcpListener::bind("127.0.0.1:0").await.unwrap());
+        let local_addr = listener.local_addr().unwrap();
+
+        let app = Router::new().route("/", get(handler));
+        let server = tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+                .await
+        });
+
+        let mut client = ClientStream::connect(local_addr).await.unwrap();
+        client
+            .write_all(b"PROXY TCP4 203.0.113.7 127.0.0.1 54321 80\r\n")
+            .await
+            .unwrap();
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        let response = String::from_utf8_lossy(&response);
+
+        assert!(response.contains("203.0.113.7:54321"));
+
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_connect_info_falls_back_to_peer_address_without_header() {
+        async fn handler(ConnectInfo(addr): ConnectInfo<SocketAddr>) -> String {
+            addr.ip().to_string()
+        }
+
+        let listener = ProxyProtocolListener::new(TcpListener::bind("127.0.0.1:0").await.unwrap());
+        let local_addr = listener.local_addr().unwrap();
+
+        let app = Router::new().route("/", get(handler));
+        let server = tokio::spawn(async move {
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+                .await
+        });
+
+        let mut client = ClientStream::connect(local_addr).await.unwrap();
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: x\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = Vec::new();
+        client.read_to_end(&mut response).await.unwrap();
+        let response = String::from_utf8_lossy(&response);
+
+        assert!(response.contains("127.0.0.1"));
+
+        server.abort();
+    }
+}
@@ -0,0 +1,125 @@
+//! PROXY protocol v1 header parsing
+//!
+//! Supports the text-based v1 header (as sent by HAProxy, AWS NLB, etc.)
+//! only; the binary v2 header is not implemented. Parsing is a pure
+//! function over a byte buffer so it can be unit tested without a real
+//! socket; [`super::proxy_listener`] is what actually reads one off a
+//! [`tokio::net::TcpStream`].
+
+use std::net::{IpAddr, SocketAddr};
+
+/// Maximum length of a v1 header, per the spec: 107 bytes including the
+/// trailing CRLF.
+pub const MAX_V1_HEADER_LEN: usize = 107;
+
+/// A successfully parsed PROXY protocol v1 header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProxyHeader {
+    /// The real client address the proxy is forwarding on behalf of.
+    pub client_addr: SocketAddr,
+    /// Number of bytes the header occupies at the start of the stream,
+    /// including the trailing CRLF, so the caller can strip exactly that
+    /// many bytes before treating the rest as ordinary connection data.
+    pub consumed: usize,
+}
+
+/// Attempts to parse a PROXY protocol v1 header from the start of `buf`.
+///
+/// Returns `None` when `buf` doesn't start with a recognizable header -
+/// callers should treat this as "no PROXY header present" and fall back to
+/// the connection's real peer address, not as a parse error, since most
+/// callers of this function only enable it when every expected client is
+/// a proxy that's known to send one.
+pub fn parse_v1(buf: &[u8]) -> Option<ProxyHeader> {
+    let search_len = buf.len().min(MAX_V1_HEADER_LEN);
+    let newline = buf[..search_len].iter().position(|&b| b == b'\n')?;
+    if newline == 0 || buf[newline - 1] != b'\r' {
+        return None;
+    }
+    let line = std::str::from_utf8(&buf[..newline - 1]).ok()?;
+    let consumed = newline + 1;
+
+    let mut fields = line.split(' ');
+    if fields.next()? != "PROXY" {
+        return None;
+    }
+    match fields.next()? {
+        "TCP4" | "TCP6" => {
+            let source_ip: IpAddr = fields.next()?.parse().ok()?;
+            let _dest_ip: IpAddr = fields.next()?.parse().ok()?;
+            let source_port: u16 = fields.next()?.parse().ok()?;
+            let _dest_port: u16 = fields.next()?.parse().ok()?;
+            if fields.next().is_some() {
+                return None;
+            }
+            Some(ProxyHeader {
+                client_addr: SocketAddr::new(source_ip, source_port),
+                consumed,
+            })
+        }
+        // "UNKNOWN" (and anything else) carries no usable address; let the
+        // caller fall back to the raw peer address instead of guessing.
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_v1_tcp4() {
+        let header =
+            parse_v1(b"PROXY TCP4 192.168.1.1 192.168.1.2 56324 443\r\nGET / HTTP/1.1\r\n")
+                .expect("should parse");
+        assert_eq!(header.client_addr, "192.168.1.1:56324".parse().unwrap());
+        assert_eq!(
+            header.consumed,
+            "PROXY TCP4 192.168.1.1 192.168.1.2 56324 443\r\n".len()
+        );
+    }
+
+    #[test]
+    fn test_parse_v1_tcp6() {
+        let header = parse_v1(b"PROXY TCP6 ::1 ::1 56324 443\r\n").expect("should parse");
+        assert_eq!(header.client_addr, "[::1]:56324".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_v1_unknown_protocol_returns_none() {
+        assert!(parse_v1(b"PROXY UNKNOWN\r\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_v1_rejects_missing_crlf() {
+        assert!(parse_v1(b"PROXY TCP4 192.168.1.1 192.168.1.2 56324 443\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_v1_rejects_garbage() {
+        assert!(parse_v1(b"GET / HTTP/1.1\r\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_v1_rejects_extra_fields() {
+        assert!(parse_v1(b"PROXY TCP4 192.168.1.1 192.168.1.2 56324 443 extra\r\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_v1_rejects_unparseable_address() {
+        assert!(parse_v1(b"PROXY TCP4 not-an-ip 192.168.1.2 56324 443\r\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_v1_returns_none_without_a_newline_in_range() {
+        let buf = vec![b'A'; MAX_V1_HEADER_LEN + 10];
+        assert!(parse_v1(&buf).is_none());
+    }
+
+    #[test]
+    fn test_parse_v1_ignores_bytes_after_header() {
+        let header = parse_v1(b"PROXY TCP4 10.0.0.1 10.0.0.2 1 2\r\nsome request body")
+            .expect("should parse");
+        assert_eq!(header.client_addr, "10.0.0.1:1".parse().unwrap());
+    }
+}
@@ -5,11 +5,9 @@
 
 use crate::Result;
 use std::path::PathBuf;
+use std::sync::Arc;
 use time::OffsetDateTime;
-use tokio::sync::{mpsc, oneshot};
-
-// Global mutex to serialize BotGuard operations to prevent V8 runtime conflicts
-static BOTGUARD_MUTEX: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+use tokio::sync::{Mutex, mpsc, oneshot};
 
 /// Commands that can be sent to the BotGuard worker
 #[allow(dead_code)]
@@ -21,7 +19,12 @@ enum BotGuardCommand {
     GetExpiryInfo {
         response: oneshot::Sender<Option<(OffsetDateTime, u32)>>,
     },
-    Shutdown,
+    Shutdown {
+        /// Signaled once the worker has finished `write_snapshot` and is
+        /// about to exit, so callers can wait on real completion instead of
+        /// guessing how long shutdown takes.
+        ack: std::sync::mpsc::Sender<()>,
+    },
 }
 
 /// BotGuard client using rustypipe-botguard crate
@@ -34,6 +37,11 @@ pub struct BotGuardClient {
     initialized: std::sync::atomic::AtomicBool,
     /// Command sender to the BotGuard worker thread
     command_tx: std::sync::Arc<tokio::sync::RwLock<Option<mpsc::UnboundedSender<BotGuardCommand>>>>,
+    /// Serializes this client's own worker operations to prevent V8 runtime
+    /// conflicts within its single worker thread. Per-instance (not a
+    /// process-global `static`) so independent `BotGuardClient`s never
+    /// serialize against each other.
+    operation_lock: Arc<Mutex<()>>,
 }
 
 impl std::fmt::Debug for BotGuardClient {
@@ -57,10 +65,40 @@ impl BotGuardClient {
             user_agent,
             initialized: std::sync::atomic::AtomicBool::new(false),
             command_tx: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
+            operation_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Build a [`rustypipe_botguard::Botguard`] instance from scratch
+    ///
+    /// Used both for the worker's normal startup and for the single retry
+    /// attempted when startup fails with a snapshot file present; see the
+    /// recovery branch in [`Self::initialize`].
+    async fn build_and_init(
+        snapshot_path: Option<&std::path::Path>,
+        user_agent: Option<&str>,
+    ) -> std::result::Result<rustypipe_botguard::Botguard, rustypipe_botguard::Error> {
+        let mut builder = rustypipe_botguard::Botguard::builder();
+
+        if let Some(path) = snapshot_path {
+            builder = builder.snapshot_path(path);
         }
+
+        if let Some(ua) = user_agent {
+            builder = builder.user_agent(ua);
+        }
+
+        builder.init().await
     }
 
     /// Initialize the BotGuard client configuration and start the worker thread
+    ///
+    /// If the first init attempt fails while a snapshot file is configured
+    /// and present on disk (e.g. left truncated by a crash mid-write), the
+    /// worker deletes it and retries once from scratch before giving up, so
+    /// a single bad snapshot doesn't strand the server. If that retry also
+    /// fails, the worker logs a [`crate::Error::BotGuard`] with code
+    /// `"snapshot_corrupt"` and exits.
     pub async fn initialize(&self) -> Result<()> {
         // Check if already initialized
         if self.initialized.load(std::sync::atomic::Ordering::Relaxed) {
@@ -98,27 +136,64 @@ impl BotGuardClient {
                 }
 
                 // Initialize Botguard once
-                let mut builder = rustypipe_botguard::Botguard::builder();
-
-                if let Some(ref path) = snapshot_path {
-                    builder = builder.snapshot_path(path);
-                }
-
-                if let Some(ref ua) = user_agent {
-                    builder = builder.user_agent(ua);
-                }
-
-                let mut botguard = match builder.init().await {
-                    Ok(bg) => bg,
-                    Err(e) => {
-                        tracing::error!("Failed to initialize BotGuard worker: {}", e);
-                        return;
-                    }
-                };
+                let mut botguard =
+                    match Self::build_and_init(snapshot_path.as_deref(), user_agent.as_deref())
+                        .await
+                    {
+                        Ok(bg) => bg,
+                        Err(e)
+                            if snapshot_path
+                                .as_deref()
+                                .is_some_and(std::path::Path::is_file) =>
+                        {
+                            // The snapshot file, if present, is the one thing we
+                            // can control and clear; a truncated/corrupt file
+                            // from a crash mid-write is the most likely
+                            // recoverable cause, so delete it and try once more
+                            // from scratch before giving up.
+                            let path = snapshot_path.as_deref().expect("checked by guard above");
+                            tracing::warn!(
+                                "BotGuard init failed ({e}) with a snapshot file present at {}; \
+                             deleting it and retrying from scratch",
+                                path.display()
+                            );
+                            if let Err(remove_err) = std::fs::remove_file(path) {
+                                tracing::warn!(
+                                    "Failed to remove snapshot file during recovery: {}",
+                                    remove_err
+                                );
+                            }
+
+                            match Self::build_and_init(
+                                snapshot_path.as_deref(),
+                                user_agent.as_deref(),
+                            )
+                            .await
+                            {
+                                Ok(bg) => bg,
+                                Err(retry_err) => {
+                                    let err = crate::Error::botguard(
+                                        "snapshot_corrupt",
+                                        &format!(
+                                            "BotGuard init still failed after deleting a possibly \
+                                         corrupt snapshot: {retry_err}"
+                                        ),
+                                    );
+                                    tracing::error!("{}", err);
+                                    return;
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to initialize BotGuard worker: {}", e);
+                            return;
+                        }
+                    };
 
                 tracing::info!("BotGuard worker initialized successfully");
 
                 // Process commands
+                let mut shutdown_ack = None;
                 while let Some(cmd) = rx.recv().await {
                     match cmd {
                         BotGuardCommand::GenerateToken {
@@ -138,8 +213,9 @@ impl BotGuardClient {
                             let valid_until = botguard.valid_until();
                             let _ = response.send(Some((valid_until, lifetime)));
                         }
-                        BotGuardCommand::Shutdown => {
+                        BotGuardCommand::Shutdown { ack } => {
                             tracing::info!("BotGuard worker shutting down");
+                            shutdown_ack = Some(ack);
                             break;
                         }
                     }
@@ -151,9 +227,25 @@ impl BotGuardClient {
                 // write_snapshot() causes the "v8::OwnedIsolate for snapshot was leaked" warning.
                 // The write_snapshot() method consumes the Botguard instance and properly
                 // extracts the snapshot data before dropping the V8 isolate.
-                match botguard.write_snapshot().await {
-                    true => tracing::debug!("BotGuard snapshot written during shutdown"),
-                    false => tracing::warn!("BotGuard snapshot write failed or not configured"),
+                //
+                // When no snapshot path is configured (e.g. `botguard.disable_snapshot`),
+                // skip the write_snapshot() call entirely rather than calling it and
+                // relying on it to no-op, so multi-replica deployments sharing no file
+                // never touch the filesystem.
+                if snapshot_path.is_some() {
+                    match botguard.write_snapshot().await {
+                        true => tracing::debug!("BotGuard snapshot written during shutdown"),
+                        false => tracing::warn!("BotGuard snapshot write failed"),
+                    }
+                } else {
+                    tracing::debug!("Running snapshot-free; skipping snapshot write on shutdown");
+                }
+
+                // Signal real completion so waiters (Drop, shutdown(),
+                // reinitialize()) can stop blocking as soon as cleanup is
+                // actually done, instead of guessing how long it takes.
+                if let Some(ack) = shutdown_ack {
+                    let _ = ack.send(());
                 }
                 tracing::info!("BotGuard worker stopped");
             });
@@ -170,14 +262,11 @@ impl BotGuardClient {
         tracing::debug!("Generating POT token for identifier: {}", identifier);
 
         if !self.initialized.load(std::sync::atomic::Ordering::Relaxed) {
-            return Err(crate::Error::botguard(
-                "not_initialized",
-                "BotGuard client not initialized. Call initialize() first.",
-            ));
+            return Err(crate::Error::not_initialized("BotGuard client"));
         }
 
-        // Acquire global mutex to serialize BotGuard operations
-        let _guard = BOTGUARD_MUTEX.lock().await;
+        // Serialize against this client's own other operations
+        let _guard = self.operation_lock.lock().await;
         tracing::debug!("Acquired BotGuard mutex for identifier: {}", identifier);
 
         // Get the command sender
@@ -220,13 +309,11 @@ impl BotGuardClient {
 
         // Shutdown existing worker if running
         if self.initialized.load(std::sync::atomic::Ordering::Relaxed) {
-            // Acquire global mutex to ensure no operations are in progress
-            let _guard = BOTGUARD_MUTEX.lock().await;
+            // Serialize against this client's own other operations
+            let _guard = self.operation_lock.lock().await;
 
             // Send shutdown command to existing worker
-            if let Some(tx) = self.command_tx.read().await.as_ref() {
-                let _ = tx.send(BotGuardCommand::Shutdown);
-            }
+            let ack_rx = self.send_shutdown_command();
 
             // Clear the command channel
             {
@@ -238,8 +325,14 @@ impl BotGuardClient {
             self.initialized
                 .store(false, std::sync::atomic::Ordering::Relaxed);
 
-            // Give the worker thread time to shutdown
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            // Wait for the worker's own completion signal instead of
+            // guessing how long shutdown takes, same as shutdown().
+            if let Some(ack_rx) = ack_rx {
+                let _ = tokio::task::spawn_blocking(move || {
+                    ack_rx.recv_timeout(Self::SHUTDOWN_ACK_TIMEOUT)
+                })
+                .await;
+            }
         }
 
         // Initialize fresh instance
@@ -252,8 +345,8 @@ impl BotGuardClient {
             return None;
         }
 
-        // Acquire global mutex to serialize BotGuard operations
-        let _guard = BOTGUARD_MUTEX.lock().await;
+        // Serialize against this client's own other operations
+        let _guard = self.operation_lock.lock().await;
 
         // Get the command sender
         let command_tx = {
@@ -320,6 +413,26 @@ impl BotGuardClient {
         None
     }
 
+    /// Send the shutdown command to the worker, if one is running.
+    ///
+    /// Returns a receiver that fires once the worker has finished
+    /// `write_snapshot` and is about to exit, or `None` if there was no
+    /// worker to signal (already shut down, or the channel lock is
+    /// contended). Uses `try_read` rather than `read().await` so this can be
+    /// called from sync contexts such as [`Drop`].
+    fn send_shutdown_command(&self) -> Option<std::sync::mpsc::Receiver<()>> {
+        let guard = self.command_tx.try_read().ok()?;
+        let tx = guard.as_ref()?;
+        let (ack_tx, ack_rx) = std::sync::mpsc::channel();
+        tx.send(BotGuardCommand::Shutdown { ack: ack_tx }).ok()?;
+        Some(ack_rx)
+    }
+
+    /// Bound on how long to wait for the worker's shutdown acknowledgement.
+    /// Only a safety net against a wedged worker - the normal case returns
+    /// as soon as the worker's `write_snapshot` actually finishes.
+    const SHUTDOWN_ACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
     /// Shutdown the BotGuard worker thread and wait for it to complete.
     /// This ensures proper cleanup of V8 isolates to avoid the
     /// "v8::OwnedIsolate for snapshot was leaked" warning.
@@ -333,10 +446,7 @@ impl BotGuardClient {
 
         tracing::debug!("Shutting down BotGuard client");
 
-        // Send shutdown command to the worker
-        if let Some(tx) = self.command_tx.read().await.as_ref() {
-            let _ = tx.send(BotGuardCommand::Shutdown);
-        }
+        let ack_rx = self.send_shutdown_command();
 
         // Clear the command channel
         {
@@ -348,29 +458,110 @@ impl BotGuardClient {
         self.initialized
             .store(false, std::sync::atomic::Ordering::Relaxed);
 
-        // Give the worker thread time to shutdown and cleanup V8 isolate
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        // Wait for the worker's own completion signal instead of guessing how
+        // long write_snapshot takes. Runs on the blocking pool so this
+        // doesn't stall the async executor while it waits.
+        if let Some(ack_rx) = ack_rx {
+            let _ = tokio::task::spawn_blocking(move || {
+                ack_rx.recv_timeout(Self::SHUTDOWN_ACK_TIMEOUT)
+            })
+            .await;
+        }
 
         tracing::debug!("BotGuard client shutdown complete");
     }
 
     /// Synchronous shutdown for use in Drop trait or when tokio runtime is not available.
-    /// This is a best-effort cleanup that sends the shutdown command without waiting.
+    ///
+    /// Sends the shutdown command and blocks until the worker acknowledges it
+    /// has finished `write_snapshot` and exited, bounded by
+    /// [`Self::SHUTDOWN_ACK_TIMEOUT`] as a safety net rather than a fixed
+    /// sleep that either wastes time or races the worker.
     pub fn shutdown_sync(&self) {
         if !self.initialized.load(std::sync::atomic::Ordering::Relaxed) {
             return;
         }
 
-        // Try to send shutdown command using blocking approach
-        // We need to use try_read to avoid blocking indefinitely
-        if let Ok(guard) = self.command_tx.try_read()
-            && let Some(tx) = guard.as_ref()
-        {
-            let _ = tx.send(BotGuardCommand::Shutdown);
-        }
+        let ack_rx = self.send_shutdown_command();
 
         self.initialized
             .store(false, std::sync::atomic::Ordering::Relaxed);
+
+        if let Some(ack_rx) = ack_rx {
+            let _ = ack_rx.recv_timeout(Self::SHUTDOWN_ACK_TIMEOUT);
+        }
+    }
+}
+
+/// Trait for BotGuard token minting to enable testing with a deterministic mock
+///
+/// Mirrors [`crate::session::innertube::InnertubeProvider`]: the real
+/// implementation ([`BotGuardClient`]) drives the V8-backed `rustypipe_botguard`
+/// worker, while tests can implement this trait with a mock that hands back a
+/// fixed token without touching V8, letting [`crate::session::SessionManagerGeneric`]
+/// stay generic over which backend it talks to.
+#[async_trait::async_trait]
+pub trait BotGuardBackend: Sized {
+    /// Construct a backend for one proxy/snapshot context. Mirrors
+    /// [`BotGuardClient::new`]'s parameters so [`crate::session::SessionManagerGeneric`]
+    /// can lazily create one per proxy cache key without knowing the concrete type.
+    fn new(snapshot_path: Option<PathBuf>, user_agent: Option<String>) -> Self;
+
+    /// Initialize the backend, starting whatever work is needed before the
+    /// first token can be minted
+    async fn initialize(&self) -> Result<()>;
+
+    /// Mint a POT token for `identifier`
+    async fn generate_po_token(&self, identifier: &str) -> Result<String>;
+
+    /// Whether [`Self::initialize`] has completed successfully
+    async fn is_initialized(&self) -> bool;
+
+    /// Discard any existing state and initialize again from scratch
+    async fn reinitialize(&self) -> Result<()>;
+
+    /// Current snapshot's expiry timestamp and lifetime in seconds, if initialized
+    async fn get_expiry_info(&self) -> Option<(OffsetDateTime, u32)>;
+
+    /// Whether the current snapshot (if any) has expired
+    async fn is_expired(&self) -> bool;
+
+    /// Release any background resources this backend is holding
+    async fn shutdown(&self);
+}
+
+#[async_trait::async_trait]
+impl BotGuardBackend for BotGuardClient {
+    fn new(snapshot_path: Option<PathBuf>, user_agent: Option<String>) -> Self {
+        Self::new(snapshot_path, user_agent)
+    }
+
+    async fn initialize(&self) -> Result<()> {
+        self.initialize().await
+    }
+
+    async fn generate_po_token(&self, identifier: &str) -> Result<String> {
+        self.generate_po_token(identifier).await
+    }
+
+    async fn is_initialized(&self) -> bool {
+        self.is_initialized().await
+    }
+
+    async fn reinitialize(&self) -> Result<()> {
+        self.reinitialize().await
+    }
+
+    async fn get_expiry_info(&self) -> Option<(OffsetDateTime, u32)> {
+        self.get_expiry_info().await
+    }
+
+    async fn is_expired(&self) -> bool {
+        self.is_expired().await
+    }
+
+    async fn shutdown(&self) {
+        self.shutdown().await
     }
 }
 
@@ -381,14 +572,10 @@ unsafe impl Sync for BotGuardClient {}
 
 impl Drop for BotGuardClient {
     fn drop(&mut self) {
-        // Perform synchronous shutdown to ensure V8 isolate cleanup
-        // This is a best-effort cleanup - we can't await in drop
+        // Can't await in Drop, so shutdown_sync() blocks on the worker's own
+        // completion signal instead of racing a fixed sleep - this ensures
+        // write_snapshot has actually finished before drop() returns.
         self.shutdown_sync();
-
-        // Give a brief moment for the shutdown command to be processed
-        // Note: This is not ideal but necessary to avoid the V8 leak warning
-        // in CLI mode where the process exits immediately
-        std::thread::sleep(std::time::Duration::from_millis(50));
     }
 }
 
@@ -418,8 +605,7 @@ mod tests {
         let client = BotGuardClient::new(None, None);
 
         let result = client.generate_po_token("test_identifier").await;
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("not initialized"));
+        assert!(matches!(result, Err(crate::Error::NotInitialized { .. })));
     }
 
     // Real integration test - may fail if network is unavailable
@@ -496,6 +682,55 @@ mod tests {
         assert!(!result.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_shutdown_does_not_write_snapshot_file_when_disabled() {
+        use tempfile::tempdir;
+
+        // Mirrors `botguard.disable_snapshot = true`: `SessionManager::new`
+        // never passes a snapshot path to `BotGuardClient::new` in that mode.
+        let temp_dir = tempdir().unwrap();
+        let snapshot_path = temp_dir.path().join("should_not_be_created.bin");
+
+        let client = BotGuardClient::new(None, None);
+        let _ = client.initialize().await;
+        client.shutdown().await;
+
+        assert!(!snapshot_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_snapshot_is_deleted_on_init_failure() {
+        use std::io::Write;
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let snapshot_path = temp_dir.path().join("corrupt_snapshot.bin");
+        std::fs::File::create(&snapshot_path)
+            .unwrap()
+            .write_all(b"not a real snapshot")
+            .unwrap();
+
+        let client = BotGuardClient::new(Some(snapshot_path.clone()), None);
+        let _ = client.initialize().await;
+
+        // initialize() hands off to a background worker thread; poll for the
+        // corrupt file to disappear instead of assuming a fixed delay.
+        let deleted = timeout(Duration::from_secs(30), async {
+            loop {
+                if !snapshot_path.exists() {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await;
+
+        assert!(
+            deleted.is_ok(),
+            "worker never deleted the corrupt snapshot file"
+        );
+    }
+
     #[tokio::test]
     async fn test_save_snapshot_with_temp_path() {
         use tempfile::tempdir;
@@ -606,4 +841,55 @@ mod tests {
         assert!(expiry1.1 > 0);
         assert!(expiry2.1 > 0);
     }
+
+    #[tokio::test]
+    async fn test_independent_clients_do_not_share_operation_lock() {
+        let client_a = BotGuardClient::new(None, None);
+        let client_b = BotGuardClient::new(None, None);
+        client_a.initialize().await.unwrap();
+        client_b.initialize().await.unwrap();
+
+        // Hold client_a's own lock well past client_b's mint time, to prove
+        // client_b isn't serializing against it.
+        let guard = client_a.operation_lock.clone().lock_owned().await;
+        let hold = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            drop(guard);
+        });
+
+        let result = timeout(
+            Duration::from_secs(3),
+            client_b.generate_po_token("independent_client_test"),
+        )
+        .await;
+
+        hold.abort();
+        assert!(
+            result.is_ok(),
+            "client_b blocked on client_a's lock instead of serializing only its own operations"
+        );
+        assert!(result.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_drop_waits_for_worker_shutdown_instead_of_racing_a_timer() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let snapshot_path = temp_dir.path().join("drop_test_snapshot.bin");
+
+        let client = BotGuardClient::new(Some(snapshot_path.clone()), None);
+        client.initialize().await.unwrap();
+
+        drop(client);
+
+        // shutdown_sync() blocks on the worker's real completion signal, so
+        // write_snapshot is guaranteed to have run by the time drop()
+        // returns - unlike the old fixed sleep, which could return before
+        // (or needlessly after) the worker actually finished.
+        assert!(
+            snapshot_path.exists(),
+            "drop() returned before the worker finished write_snapshot"
+        );
+    }
 }
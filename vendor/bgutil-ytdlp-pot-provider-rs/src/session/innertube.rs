@@ -6,16 +6,42 @@
 use crate::Result;
 use reqwest::Client;
 
+use super::network::{NetworkManager, RequestOptions};
+
+/// Default retry/timeout options for Innertube requests - a handful of
+/// attempts with a short base backoff is enough to ride out a transient
+/// network blip or a brief rate limit without holding up a mint for too
+/// long. Connect/request timeouts come from `network.connect_timeout`/
+/// `network.request_timeout`, so operators can tune them without a code
+/// change.
+pub(crate) fn default_options(
+    settings: &crate::config::settings::NetworkSettings,
+) -> RequestOptions {
+    RequestOptions::from_network_settings(settings)
+        .with_max_retries(3)
+        .with_retry_interval_ms(200)
+}
+
 /// Trait for Innertube API operations to enable testing with mocks
 #[async_trait::async_trait]
 pub trait InnertubeProvider {
     /// Generate visitor data from YouTube's Innertube API
-    async fn generate_visitor_data(&self) -> Result<String>;
+    ///
+    /// `user_agent`, when set, overrides the client's default User-Agent for
+    /// this request only - used to honor a User-Agent picked from
+    /// `network.user_agent_pool` for a single mint. `options` carries the
+    /// connect/request timeouts and retry policy applied to the request.
+    async fn generate_visitor_data(
+        &self,
+        user_agent: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<String>;
 
     /// Get challenge data from Innertube /att/get endpoint
     async fn get_challenge(
         &self,
         context: &crate::types::InnertubeContext,
+        options: &RequestOptions,
     ) -> crate::Result<crate::types::ChallengeData>;
 }
 
@@ -26,6 +52,8 @@ pub struct InnertubeClient {
     client: Client,
     /// Base URL for Innertube API
     base_url: String,
+    /// `clientName` sent with visitor-data generation requests
+    client_name: crate::config::InnertubeClientName,
 }
 
 impl InnertubeClient {
@@ -34,12 +62,24 @@ impl InnertubeClient {
         Self {
             client,
             base_url: "https://www.youtube.com/youtubei/v1".to_string(),
+            client_name: crate::config::InnertubeClientName::default(),
         }
     }
 
     /// Create new Innertube client with custom base URL (for testing)
     pub fn new_with_base_url(client: Client, base_url: String) -> Self {
-        Self { client, base_url }
+        Self {
+            client,
+            base_url,
+            client_name: crate::config::InnertubeClientName::default(),
+        }
+    }
+
+    /// Override the `clientName` sent with visitor-data generation
+    /// requests, from [`crate::config::settings::NetworkSettings::innertube_client_name`]
+    pub fn with_client_name(mut self, client_name: crate::config::InnertubeClientName) -> Self {
+        self.client_name = client_name;
+        self
     }
 }
 
@@ -48,13 +88,55 @@ impl InnertubeProvider for InnertubeClient {
     /// Generate visitor data
     ///
     /// Corresponds to TypeScript: `generateVisitorData` method (L230-241)
-    async fn generate_visitor_data(&self) -> Result<String> {
+    ///
+    /// Retried with [`NetworkManager::retry_with_backoff`] since a rate limit
+    /// or a transient network error here shouldn't fail the whole mint.
+    async fn generate_visitor_data(
+        &self,
+        user_agent: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<String> {
+        NetworkManager::retry_with_backoff(
+            || self.try_generate_visitor_data(user_agent, options),
+            options.clone(),
+        )
+        .await
+    }
+
+    /// Get challenge data from Innertube /att/get endpoint
+    ///
+    /// Corresponds to TypeScript: POST to /youtubei/v1/att/get in getDescrambledChallenge method
+    ///
+    /// Retried with [`NetworkManager::retry_with_backoff`] for the same reason
+    /// as [`InnertubeClient::generate_visitor_data`].
+    async fn get_challenge(
+        &self,
+        context: &crate::types::InnertubeContext,
+        options: &RequestOptions,
+    ) -> crate::Result<crate::types::ChallengeData> {
+        NetworkManager::retry_with_backoff(
+            || self.try_get_challenge(context, options),
+            options.clone(),
+        )
+        .await
+    }
+}
+
+impl InnertubeClient {
+    /// Generate visitor data
+    ///
+    /// Corresponds to TypeScript: `generateVisitorData` method (L230-241)
+    async fn try_generate_visitor_data(
+        &self,
+        user_agent: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<String> {
         use serde_json::json;
 
         let request_body = json!({
             "context": {
                 "client": {
-                    "clientName": "WEB",
+                    "clientName": self.client_name.as_str(),
                     "clientVersion": "2.20240822.03.00",
                     "hl": "en",
                     "gl": "US"
@@ -63,14 +145,16 @@ impl InnertubeProvider for InnertubeClient {
             "browseId": "FEwhat_to_watch"
         });
 
-        let response = self
+        let mut builder = self
             .client
             .post(format!("{}/browse", self.base_url))
             .header("Content-Type", "application/json")
-            .header(
-                "User-Agent",
-                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
-            )
+            .timeout(options.request_timeout);
+        if let Some(ua) = user_agent {
+            builder = builder.header(reqwest::header::USER_AGENT, ua);
+        }
+
+        let response = builder
             .json(&request_body)
             .send()
             .await
@@ -82,6 +166,18 @@ impl InnertubeProvider for InnertubeClient {
                 }
             })?;
 
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = parse_retry_after(response.headers());
+            tracing::error!(
+                "Innertube API rate limited us, retry after: {:?}",
+                retry_after
+            );
+            return Err(crate::Error::rate_limit(
+                "Innertube API rate limited the visitor data request",
+                retry_after,
+            ));
+        }
+
         if !response.status().is_success() {
             let status = response.status();
             tracing::error!("Innertube API returned error status: {}", status);
@@ -118,9 +214,10 @@ impl InnertubeProvider for InnertubeClient {
     /// Get challenge data from Innertube /att/get endpoint
     ///
     /// Corresponds to TypeScript: POST to /youtubei/v1/att/get in getDescrambledChallenge method
-    async fn get_challenge(
+    async fn try_get_challenge(
         &self,
         context: &crate::types::InnertubeContext,
+        options: &RequestOptions,
     ) -> crate::Result<crate::types::ChallengeData> {
         use serde_json::json;
 
@@ -135,10 +232,7 @@ impl InnertubeProvider for InnertubeClient {
             .client
             .post(format!("{}/att/get?prettyPrint=false", self.base_url))
             .header("Content-Type", "application/json")
-            .header(
-                "User-Agent",
-                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
-            )
+            .timeout(options.request_timeout)
             .json(&request_body)
             .send()
             .await
@@ -215,6 +309,17 @@ impl InnertubeProvider for InnertubeClient {
     }
 }
 
+/// Parse the `Retry-After` header value (in seconds) from a response
+///
+/// Only the delay-seconds form is supported; YouTube's Innertube API does not
+/// send the HTTP-date form in practice.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+}
+
 impl InnertubeClient {
     /// Get client configuration for diagnostics
     pub fn get_client_info(&self) -> (String, bool) {
@@ -275,7 +380,9 @@ mod tests {
         innertube.base_url = mock_server.uri() + "/youtubei/v1";
 
         // Act
-        let result = innertube.generate_visitor_data().await;
+        let result = innertube
+            .generate_visitor_data(None, &default_options(&Default::default()))
+            .await;
 
         // Assert
         assert!(result.is_ok());
@@ -284,6 +391,90 @@ mod tests {
         assert!(!generated_visitor_data.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_generate_visitor_data_honors_client_name_override() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        let visitor_data = "CgtDZjBSbE5uZDJlQSij6bbFBjIKCgJVUxIEGgAgYA%3D%3D";
+
+        let expected_request = json!({
+            "context": {
+                "client": {
+                    "clientName": "TVHTML5",
+                    "clientVersion": "2.20240822.03.00",
+                    "hl": "en",
+                    "gl": "US"
+                }
+            },
+            "browseId": "FEwhat_to_watch"
+        });
+
+        let mock_response = json!({
+            "responseContext": {
+                "visitorData": visitor_data
+            }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/youtubei/v1/browse"))
+            .and(body_json(&expected_request))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_response))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new();
+        let mut innertube = InnertubeClient::new(client)
+            .with_client_name(crate::config::InnertubeClientName::Tvhtml5);
+        innertube.base_url = mock_server.uri() + "/youtubei/v1";
+
+        // Act
+        let result = innertube
+            .generate_visitor_data(None, &default_options(&Default::default()))
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), visitor_data);
+    }
+
+    #[tokio::test]
+    async fn test_generate_visitor_data_honors_user_agent_override() {
+        use wiremock::matchers::header;
+
+        // Arrange
+        let mock_server = MockServer::start().await;
+        let visitor_data = "CgtDZjBSbE5uZDJlQSij6bbFBjIKCgJVUxIEGgAgYA%3D%3D";
+
+        let mock_response = json!({
+            "responseContext": {
+                "visitorData": visitor_data
+            }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/youtubei/v1/browse"))
+            .and(header("User-Agent", "pool-agent/1.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mock_response))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new();
+        let mut innertube = InnertubeClient::new(client);
+        innertube.base_url = mock_server.uri() + "/youtubei/v1";
+
+        // Act
+        let result = innertube
+            .generate_visitor_data(
+                Some("pool-agent/1.0"),
+                &default_options(&Default::default()),
+            )
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), visitor_data);
+    }
+
     #[tokio::test]
     async fn test_generate_visitor_data_network_error() {
         // Arrange
@@ -292,7 +483,9 @@ mod tests {
         innertube.base_url = "http://invalid-url-that-does-not-exist".to_string();
 
         // Act
-        let result = innertube.generate_visitor_data().await;
+        let result = innertube
+            .generate_visitor_data(None, &default_options(&Default::default()))
+            .await;
 
         // Assert
         assert!(result.is_err());
@@ -321,12 +514,82 @@ mod tests {
         innertube.base_url = mock_server.uri() + "/youtubei/v1";
 
         // Act
-        let result = innertube.generate_visitor_data().await;
+        let result = innertube
+            .generate_visitor_data(None, &default_options(&Default::default()))
+            .await;
 
         // Assert
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_generate_visitor_data_times_out_on_slow_endpoint() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/youtubei/v1/browse"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"responseContext": {"visitorData": "irrelevant"}}))
+                    .set_delay(std::time::Duration::from_secs(5)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new();
+        let mut innertube = InnertubeClient::new(client);
+        innertube.base_url = mock_server.uri() + "/youtubei/v1";
+
+        let options = RequestOptions::new()
+            .with_max_retries(1)
+            .with_request_timeout(std::time::Duration::from_millis(50));
+
+        // Act
+        let result = innertube.generate_visitor_data(None, &options).await;
+
+        // Assert
+        assert!(result.is_err());
+        let error_str = result.unwrap_err().to_string();
+        assert!(
+            error_str.contains("Visitor data generation failed")
+                || error_str.contains("Network request failed")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_visitor_data_rate_limited() {
+        // Arrange
+        //
+        // Kept to 1 second so that retry_with_backoff's retries (which sleep
+        // exactly retry_after between attempts) don't make this test slow.
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/youtubei/v1/browse"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new();
+        let mut innertube = InnertubeClient::new(client);
+        innertube.base_url = mock_server.uri() + "/youtubei/v1";
+
+        // Act
+        let result = innertube
+            .generate_visitor_data(None, &default_options(&Default::default()))
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            crate::Error::RateLimit { retry_after, .. } => {
+                assert_eq!(retry_after, Some(1));
+            }
+            other => panic!("Expected RateLimit error, got: {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_generate_visitor_data_missing_visitor_data() {
         // Arrange
@@ -347,7 +610,9 @@ mod tests {
         innertube.base_url = mock_server.uri() + "/youtubei/v1";
 
         // Act
-        let result = innertube.generate_visitor_data().await;
+        let result = innertube
+            .generate_visitor_data(None, &default_options(&Default::default()))
+            .await;
 
         // Assert
         assert!(result.is_err());
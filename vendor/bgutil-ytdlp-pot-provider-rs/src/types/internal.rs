@@ -15,6 +15,9 @@ pub struct SessionData {
     pub content_binding: String,
     /// Expiration timestamp
     pub expires_at: DateTime<Utc>,
+    /// Whether `po_token` is the minter's websafe fallback token, served
+    /// because the primary mint for `content_binding` failed
+    pub is_fallback: bool,
 }
 
 impl SessionData {
@@ -28,9 +31,16 @@ impl SessionData {
             po_token: po_token.into(),
             content_binding: content_binding.into(),
             expires_at,
+            is_fallback: false,
         }
     }
 
+    /// Mark this session data as served from the websafe fallback token
+    pub fn with_fallback(mut self) -> Self {
+        self.is_fallback = true;
+        self
+    }
+
     /// Check if session data has expired
     pub fn is_expired(&self) -> bool {
         Utc::now() > self.expires_at
@@ -42,14 +52,30 @@ impl SessionData {
     }
 }
 
+/// Whether `s` looks like an 11-character YouTube video ID
+///
+/// Shared by [`PotRequest::validate`](crate::types::PotRequest::validate) (to
+/// reject an explicit [`PotTokenType::ContentBound`] override whose binding
+/// isn't video-id-shaped) and the session manager's own content-binding
+/// heuristic.
+pub(crate) fn is_video_id_format(s: &str) -> bool {
+    s.len() == 11
+        && s.chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+}
+
 /// POT token types corresponding to different contexts
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
 pub enum PotTokenType {
     /// Session-bound POT token using visitor_data as identifier
     #[default]
     SessionBound,
     /// Content-bound POT token using video_id as identifier
     ContentBound,
+    /// Token bound to both visitor_data and video_id at once, for newer
+    /// YouTube flows that reject a token scoped to either alone
+    SessionAndContentBound,
     /// Cold-start POT token using placeholder implementation
     ColdStart,
 }
@@ -59,7 +85,7 @@ pub enum PotTokenType {
 pub struct PotContext {
     /// Visitor data for session-bound tokens
     pub visitor_data: String,
-    /// Video ID for content-bound tokens (optional)
+    /// Video ID for content-bound and session-and-content-bound tokens (optional)
     pub video_id: Option<String>,
     /// Token type to generate
     pub token_type: PotTokenType,
@@ -257,7 +283,11 @@ impl InnertubeContext {
 }
 
 /// Client information for Innertube
+///
+/// `#[serde(rename_all = "camelCase")]` matches the `remoteHost`/`visitorData`
+/// keys callers already send inside `PotRequest::innertube_context`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClientInfo {
     /// Remote host
     pub remote_host: Option<String>,
@@ -460,6 +490,16 @@ mod tests {
         assert_eq!(context.video_id, Some("dQw4w9WgXcQ".to_string()));
     }
 
+    #[test]
+    fn test_pot_context_session_and_content_bound_carries_both_fields() {
+        let context = PotContext::new("test_visitor", PotTokenType::SessionAndContentBound)
+            .with_video_id("dQw4w9WgXcQ");
+
+        assert_eq!(context.visitor_data, "test_visitor");
+        assert_eq!(context.token_type, PotTokenType::SessionAndContentBound);
+        assert_eq!(context.video_id, Some("dQw4w9WgXcQ".to_string()));
+    }
+
     #[test]
     fn test_pot_token_result_creation() {
         let expires_at = SystemTime::now() + std::time::Duration::from_secs(3600);
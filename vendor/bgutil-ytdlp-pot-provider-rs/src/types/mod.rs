@@ -7,5 +7,9 @@ pub mod request;
 pub mod response;
 
 pub use internal::*;
-pub use request::{InvalidateRequest, InvalidationType, PotRequest};
-pub use response::{ErrorResponse, MinterCacheResponse, PingResponse, PotResponse};
+pub use request::{InvalidateRequest, InvalidationType, PotRequest, TokenContext, WarmupRequest};
+pub use response::{
+    CachePruneResponse, CacheStatsResponse, DeepHealthResponse, DiagnosticsResponse, ErrorResponse,
+    MinterCacheDetailEntry, MinterCacheResponse, PingResponse, PotResponse, ReinitializeResponse,
+    SessionCacheEntry, VersionResponse, WarmupResponse,
+};
@@ -2,6 +2,7 @@
 //!
 //! Defines the structure for POT token generation requests.
 
+use super::internal::PotTokenType;
 use serde::{Deserialize, Serialize};
 
 /// BotGuard challenge data structure
@@ -71,6 +72,73 @@ pub struct PotRequest {
 
     /// Client-side IP address to bind to
     pub source_address: Option<String>,
+
+    /// Data sync ID for a session-bound mint
+    ///
+    /// Used as the content binding when `content_binding` itself is absent,
+    /// for logged-in YouTube sessions that need a token bound to their data
+    /// sync ID rather than a video ID or visitor data. Unlike the removed
+    /// `visitor_data` field, this has one consistent meaning and is no
+    /// longer rejected by [`crate::server::handlers::validate_deprecated_fields_middleware`].
+    pub data_sync_id: Option<String>,
+
+    /// Video ID to pair with `content_binding` for a
+    /// [`PotTokenType::SessionAndContentBound`] mint
+    ///
+    /// Ignored unless `token_type` is set to
+    /// [`PotTokenType::SessionAndContentBound`], in which case
+    /// `content_binding` is used as the visitor data half of the pair and
+    /// this field supplies the video ID half; see [`Self::validate`].
+    pub video_id: Option<String>,
+
+    /// YouTube surface this token is scoped to (GVS, player, subs)
+    ///
+    /// Distinguishes tokens that share a content binding but are minted for
+    /// different surfaces, so a subtitle token can't be served back for a
+    /// player request (or vice versa).
+    pub token_context: Option<TokenContext>,
+
+    /// Override the session manager's content-binding heuristic and mint
+    /// this specific [`PotTokenType`] instead
+    ///
+    /// Unset lets the session manager decide how to mint based on
+    /// `content_binding` alone, which is the right choice for almost every
+    /// caller. [`PotTokenType::ContentBound`] requires `content_binding` to
+    /// be a bare, video-id-shaped binding; see [`Self::validate`].
+    pub token_type: Option<PotTokenType>,
+
+    /// Mint a [`PotTokenType::ColdStart`] token instead of letting the
+    /// content-binding heuristic decide
+    ///
+    /// Shorthand for `token_type: Some(PotTokenType::ColdStart)`; ignored
+    /// when `token_type` is already set, since that's the more specific
+    /// override.
+    pub cold_start: Option<bool>,
+}
+
+/// YouTube surface a POT token is scoped to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenContext {
+    /// General visitor session tokens
+    #[serde(rename = "gvs")]
+    Gvs,
+    /// Video player-specific tokens
+    #[serde(rename = "player")]
+    Player,
+    /// Subtitle/captions tokens
+    #[serde(rename = "subs")]
+    Subs,
+}
+
+impl TokenContext {
+    /// Stable wire/cache-key representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gvs => "gvs",
+            Self::Player => "player",
+            Self::Subs => "subs",
+        }
+    }
 }
 
 /// Challenge invalidation request
@@ -97,6 +165,13 @@ impl InvalidateRequest {
     }
 }
 
+/// Request to pre-mint and cache tokens for a list of content bindings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupRequest {
+    /// Content bindings to warm up
+    pub content_bindings: Vec<String>,
+}
+
 /// Type of invalidation operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum InvalidationType {
@@ -118,6 +193,11 @@ impl Default for PotRequest {
             disable_tls_verification: Some(false),
             innertube_context: None,
             source_address: None,
+            data_sync_id: None,
+            video_id: None,
+            token_context: None,
+            token_type: None,
+            cold_start: None,
         }
     }
 }
@@ -152,6 +232,19 @@ impl PotRequest {
         self
     }
 
+    /// Set the data sync ID for a session-bound mint
+    pub fn with_data_sync_id(mut self, data_sync_id: impl Into<String>) -> Self {
+        self.data_sync_id = Some(data_sync_id.into());
+        self
+    }
+
+    /// Set the video ID to pair with `content_binding` for a
+    /// [`PotTokenType::SessionAndContentBound`] mint
+    pub fn with_video_id(mut self, video_id: impl Into<String>) -> Self {
+        self.video_id = Some(video_id.into());
+        self
+    }
+
     /// Set TLS verification flag
     pub fn with_disable_tls_verification(mut self, disable: bool) -> Self {
         self.disable_tls_verification = Some(disable);
@@ -181,6 +274,123 @@ impl PotRequest {
         self.innertube_context = Some(context);
         self
     }
+
+    /// Set Innertube context from a typed [`ClientInfo`], rather than a raw
+    /// [`serde_json::Value`]
+    ///
+    /// Preferred over [`Self::with_innertube_context`] when the caller
+    /// already has structured client info: it can't produce a context shape
+    /// `create_cache_key` fails to parse.
+    pub fn with_innertube_client(mut self, client: super::internal::ClientInfo) -> Self {
+        let context = super::internal::InnertubeContext::new(client);
+        self.innertube_context = Some(
+            serde_json::to_value(context).expect("InnertubeContext serializes infallibly"),
+        );
+        self
+    }
+
+    /// Set the YouTube surface (GVS, player, subs) this token is scoped to
+    pub fn with_token_context(mut self, token_context: TokenContext) -> Self {
+        self.token_context = Some(token_context);
+        self
+    }
+
+    /// Override the content-binding heuristic and mint this specific
+    /// [`PotTokenType`] instead
+    pub fn with_token_type(mut self, token_type: PotTokenType) -> Self {
+        self.token_type = Some(token_type);
+        self
+    }
+
+    /// Request a [`PotTokenType::ColdStart`] mint instead of letting the
+    /// content-binding heuristic decide
+    pub fn with_cold_start(mut self, cold_start: bool) -> Self {
+        self.cold_start = Some(cold_start);
+        self
+    }
+
+    /// Resolve the effective [`PotTokenType`] override for this request,
+    /// folding `cold_start` into `token_type` when the latter is unset
+    pub fn effective_token_type(&self) -> Option<PotTokenType> {
+        self.token_type.or_else(|| {
+            self.cold_start
+                .unwrap_or(false)
+                .then_some(PotTokenType::ColdStart)
+        })
+    }
+
+    /// Validate request fields before token generation
+    ///
+    /// Catches malformed `proxy`/`source_address`/`content_binding` values early
+    /// so callers get a clear [`crate::Error::Validation`] instead of a confusing
+    /// failure deeper in proxy setup or BotGuard.
+    pub fn validate(&self) -> crate::Result<()> {
+        if let Some(proxy) = &self.proxy
+            && url::Url::parse(proxy).is_err()
+        {
+            return Err(crate::Error::validation_with_value(
+                "proxy",
+                "must be a valid URL",
+                proxy.as_str(),
+            ));
+        }
+
+        if let Some(source_address) = &self.source_address
+            && source_address.parse::<std::net::IpAddr>().is_err()
+        {
+            return Err(crate::Error::validation_with_value(
+                "source_address",
+                "must be a valid IP address",
+                source_address.as_str(),
+            ));
+        }
+
+        if let Some(content_binding) = &self.content_binding
+            && content_binding.trim().is_empty()
+        {
+            return Err(crate::Error::validation(
+                "content_binding",
+                "must not be empty or whitespace",
+            ));
+        }
+
+        if let Some(data_sync_id) = &self.data_sync_id
+            && data_sync_id.trim().is_empty()
+        {
+            return Err(crate::Error::validation(
+                "data_sync_id",
+                "must not be empty or whitespace",
+            ));
+        }
+
+        if self.token_type == Some(PotTokenType::ContentBound)
+            && !self
+                .content_binding
+                .as_deref()
+                .is_some_and(super::internal::is_video_id_format)
+        {
+            return Err(crate::Error::missing_video_id());
+        }
+
+        if self.token_type == Some(PotTokenType::SessionAndContentBound) {
+            if self.content_binding.as_deref().is_none_or(str::is_empty) {
+                return Err(crate::Error::validation(
+                    "content_binding",
+                    "must be set alongside video_id for a session_and_content_bound mint",
+                ));
+            }
+
+            if !self
+                .video_id
+                .as_deref()
+                .is_some_and(super::internal::is_video_id_format)
+            {
+                return Err(crate::Error::missing_video_id());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -416,6 +626,268 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pot_request_with_token_context() {
+        let request = PotRequest::new()
+            .with_content_binding("test_video_id")
+            .with_token_context(TokenContext::Player);
+
+        assert_eq!(request.token_context, Some(TokenContext::Player));
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"player\""));
+
+        let deserialized: PotRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.token_context, Some(TokenContext::Player));
+    }
+
+    #[test]
+    fn test_pot_request_token_context_defaults_to_none() {
+        let request = PotRequest::default();
+        assert_eq!(request.token_context, None);
+    }
+
+    #[test]
+    fn test_pot_request_with_token_type() {
+        let request = PotRequest::new()
+            .with_content_binding("dQw4w9WgXcQ")
+            .with_token_type(PotTokenType::ContentBound);
+
+        assert_eq!(request.token_type, Some(PotTokenType::ContentBound));
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"content_bound\""));
+
+        let deserialized: PotRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.token_type, Some(PotTokenType::ContentBound));
+    }
+
+    #[test]
+    fn test_pot_request_token_type_defaults_to_none() {
+        let request = PotRequest::default();
+        assert_eq!(request.token_type, None);
+    }
+
+    #[test]
+    fn test_pot_request_with_cold_start() {
+        let request = PotRequest::new().with_cold_start(true);
+
+        assert_eq!(request.cold_start, Some(true));
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"cold_start\":true"));
+
+        let deserialized: PotRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.cold_start, Some(true));
+    }
+
+    #[test]
+    fn test_pot_request_cold_start_defaults_to_none() {
+        assert_eq!(PotRequest::new().cold_start, None);
+    }
+
+    #[test]
+    fn test_effective_token_type_folds_cold_start_into_token_type() {
+        let request = PotRequest::new().with_cold_start(true);
+        assert_eq!(
+            request.effective_token_type(),
+            Some(PotTokenType::ColdStart)
+        );
+    }
+
+    #[test]
+    fn test_effective_token_type_prefers_explicit_token_type_over_cold_start() {
+        let request = PotRequest::new()
+            .with_cold_start(true)
+            .with_token_type(PotTokenType::SessionBound);
+
+        assert_eq!(
+            request.effective_token_type(),
+            Some(PotTokenType::SessionBound)
+        );
+    }
+
+    #[test]
+    fn test_effective_token_type_is_none_when_neither_is_set() {
+        assert_eq!(PotRequest::new().effective_token_type(), None);
+    }
+
+    #[test]
+    fn test_validate_accepts_content_bound_with_video_id_shaped_binding() {
+        let request = PotRequest::new()
+            .with_content_binding("dQw4w9WgXcQ")
+            .with_token_type(PotTokenType::ContentBound);
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_content_bound_without_video_id_shaped_binding() {
+        let request = PotRequest::new()
+            .with_content_binding("not-video-id-shaped")
+            .with_token_type(PotTokenType::ContentBound);
+
+        assert!(matches!(
+            request.validate().unwrap_err(),
+            crate::Error::MissingVideoId
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_content_bound_with_no_binding_at_all() {
+        let request = PotRequest::new().with_token_type(PotTokenType::ContentBound);
+
+        assert!(matches!(
+            request.validate().unwrap_err(),
+            crate::Error::MissingVideoId
+        ));
+    }
+
+    #[test]
+    fn test_validate_accepts_session_bound_with_any_binding() {
+        let request = PotRequest::new()
+            .with_content_binding("not-video-id-shaped")
+            .with_token_type(PotTokenType::SessionBound);
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_pot_request_with_video_id() {
+        let request = PotRequest::new()
+            .with_content_binding("visitor_data_value")
+            .with_video_id("dQw4w9WgXcQ")
+            .with_token_type(PotTokenType::SessionAndContentBound);
+
+        assert_eq!(request.video_id, Some("dQw4w9WgXcQ".to_string()));
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"session_and_content_bound\""));
+
+        let deserialized: PotRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.video_id, Some("dQw4w9WgXcQ".to_string()));
+    }
+
+    #[test]
+    fn test_pot_request_video_id_defaults_to_none() {
+        let request = PotRequest::default();
+        assert_eq!(request.video_id, None);
+    }
+
+    #[test]
+    fn test_validate_accepts_session_and_content_bound_with_both_fields() {
+        let request = PotRequest::new()
+            .with_content_binding("visitor_data_value")
+            .with_video_id("dQw4w9WgXcQ")
+            .with_token_type(PotTokenType::SessionAndContentBound);
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_session_and_content_bound_without_video_id() {
+        let request = PotRequest::new()
+            .with_content_binding("visitor_data_value")
+            .with_token_type(PotTokenType::SessionAndContentBound);
+
+        assert!(matches!(
+            request.validate().unwrap_err(),
+            crate::Error::MissingVideoId
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_session_and_content_bound_with_non_video_id_shaped_video_id() {
+        let request = PotRequest::new()
+            .with_content_binding("visitor_data_value")
+            .with_video_id("not-video-id-shaped")
+            .with_token_type(PotTokenType::SessionAndContentBound);
+
+        assert!(matches!(
+            request.validate().unwrap_err(),
+            crate::Error::MissingVideoId
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_session_and_content_bound_without_content_binding() {
+        let request = PotRequest::new()
+            .with_video_id("dQw4w9WgXcQ")
+            .with_token_type(PotTokenType::SessionAndContentBound);
+
+        assert!(matches!(
+            request.validate().unwrap_err(),
+            crate::Error::Validation { .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_accepts_empty_request() {
+        assert!(PotRequest::new().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_accepts_fully_populated_request() {
+        let request = PotRequest::new()
+            .with_content_binding("test_video_id")
+            .with_proxy("http://proxy:8080")
+            .with_source_address("192.168.1.1");
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_proxy() {
+        let request = PotRequest::new().with_proxy("not a url");
+        let err = request.validate().unwrap_err();
+        assert!(matches!(err, crate::Error::Validation { ref field, .. } if field == "proxy"));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_source_address() {
+        let request = PotRequest::new().with_source_address("not an ip");
+        let err = request.validate().unwrap_err();
+        assert!(
+            matches!(err, crate::Error::Validation { ref field, .. } if field == "source_address")
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_whitespace_content_binding() {
+        let request = PotRequest::new().with_content_binding("   ");
+        let err = request.validate().unwrap_err();
+        assert!(
+            matches!(err, crate::Error::Validation { ref field, .. } if field == "content_binding")
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_whitespace_data_sync_id() {
+        let request = PotRequest::new().with_data_sync_id("   ");
+        let err = request.validate().unwrap_err();
+        assert!(
+            matches!(err, crate::Error::Validation { ref field, .. } if field == "data_sync_id")
+        );
+    }
+
+    #[test]
+    fn test_pot_request_with_data_sync_id() {
+        let request = PotRequest::new().with_data_sync_id("sync_id_123");
+        assert_eq!(request.data_sync_id, Some("sync_id_123".to_string()));
+    }
+
+    #[test]
+    fn test_pot_request_data_sync_id_defaults_to_none() {
+        assert_eq!(PotRequest::new().data_sync_id, None);
+    }
+
+    #[test]
+    fn test_token_context_as_str() {
+        assert_eq!(TokenContext::Gvs.as_str(), "gvs");
+        assert_eq!(TokenContext::Player.as_str(), "player");
+        assert_eq!(TokenContext::Subs.as_str(), "subs");
+    }
+
     #[test]
     fn test_interpreter_url_serialization() {
         let url = InterpreterUrl {
@@ -6,7 +6,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Response for POT token generation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "PotResponseWire")]
 pub struct PotResponse {
     /// The generated POT token
     #[serde(rename = "poToken")]
@@ -19,6 +20,90 @@ pub struct PotResponse {
     /// Token expiration timestamp
     #[serde(rename = "expiresAt")]
     pub expires_at: DateTime<Utc>,
+
+    /// The YouTube surface (GVS, player, subs) this token is scoped to
+    #[serde(rename = "tokenContext", skip_serializing_if = "Option::is_none")]
+    pub token_context: Option<crate::types::TokenContext>,
+
+    /// `true` if the primary mint for `content_binding` failed and this is
+    /// the minter's websafe fallback token instead. Omitted entirely when
+    /// `false`, so existing callers that never see a fallback see no change.
+    #[serde(rename = "isFallback", skip_serializing_if = "Option::is_none")]
+    pub is_fallback: Option<bool>,
+
+    /// The [`crate::types::internal::PotTokenType`] actually minted, when a
+    /// caller asked for one explicitly (via `token_type` or `cold_start`).
+    /// Omitted for the default content-binding-heuristic mint, so existing
+    /// callers see no change.
+    #[serde(rename = "tokenType", skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<crate::types::internal::PotTokenType>,
+
+    /// `true` if the mint failed and this is an already-expired cached
+    /// token served anyway because `token.serve_stale_on_error` is set.
+    /// Omitted entirely when `false`, so existing callers that never see a
+    /// stale fallback see no change.
+    #[serde(rename = "isStale", skip_serializing_if = "Option::is_none")]
+    pub is_stale: Option<bool>,
+
+    /// Host (no scheme, no credentials) of the proxy used for this request,
+    /// for debugging proxy-specific token issues. `None` when the request
+    /// didn't use a proxy.
+    #[serde(rename = "viaProxy", skip_serializing_if = "Option::is_none")]
+    pub via_proxy: Option<String>,
+}
+
+/// Serialization shape for [`PotResponse`], adding the computed
+/// `remainingSeconds` field. Kept separate from `PotResponse` itself (rather
+/// than a stored field) so it's always derived from `expires_at` at the
+/// moment of serialization and never round-trips through deserialization or
+/// the file cache.
+#[derive(Serialize)]
+struct PotResponseWire {
+    #[serde(rename = "poToken")]
+    po_token: String,
+
+    #[serde(rename = "contentBinding")]
+    content_binding: String,
+
+    #[serde(rename = "expiresAt")]
+    expires_at: DateTime<Utc>,
+
+    #[serde(rename = "tokenContext", skip_serializing_if = "Option::is_none")]
+    token_context: Option<crate::types::TokenContext>,
+
+    #[serde(rename = "isFallback", skip_serializing_if = "Option::is_none")]
+    is_fallback: Option<bool>,
+
+    #[serde(rename = "tokenType", skip_serializing_if = "Option::is_none")]
+    token_type: Option<crate::types::internal::PotTokenType>,
+
+    #[serde(rename = "isStale", skip_serializing_if = "Option::is_none")]
+    is_stale: Option<bool>,
+
+    #[serde(rename = "viaProxy", skip_serializing_if = "Option::is_none")]
+    via_proxy: Option<String>,
+
+    /// Seconds remaining until `expires_at`, clamped at 0 for an already
+    /// expired token
+    #[serde(rename = "remainingSeconds")]
+    remaining_seconds: i64,
+}
+
+impl From<PotResponse> for PotResponseWire {
+    fn from(response: PotResponse) -> Self {
+        let remaining_seconds = (response.expires_at - Utc::now()).num_seconds().max(0);
+        Self {
+            po_token: response.po_token,
+            content_binding: response.content_binding,
+            expires_at: response.expires_at,
+            token_context: response.token_context,
+            is_fallback: response.is_fallback,
+            token_type: response.token_type,
+            is_stale: response.is_stale,
+            via_proxy: response.via_proxy,
+            remaining_seconds,
+        }
+    }
 }
 
 impl PotResponse {
@@ -32,6 +117,11 @@ impl PotResponse {
             po_token: po_token.into(),
             content_binding: content_binding.into(),
             expires_at,
+            token_context: None,
+            is_fallback: None,
+            token_type: None,
+            is_stale: None,
+            via_proxy: None,
         }
     }
 
@@ -45,12 +135,43 @@ impl PotResponse {
         self.expires_at - Utc::now()
     }
 
+    /// Set the YouTube surface this token is scoped to
+    pub fn with_token_context(mut self, token_context: crate::types::TokenContext) -> Self {
+        self.token_context = Some(token_context);
+        self
+    }
+
+    /// Record the [`crate::types::internal::PotTokenType`] actually minted
+    pub fn with_token_type(mut self, token_type: crate::types::internal::PotTokenType) -> Self {
+        self.token_type = Some(token_type);
+        self
+    }
+
+    /// Mark this response as an expired cached token served in place of a
+    /// failed fresh mint, per `token.serve_stale_on_error`
+    pub fn with_stale(mut self) -> Self {
+        self.is_stale = Some(true);
+        self
+    }
+
+    /// Record the host of the proxy used for this request, for debugging
+    /// proxy-specific token issues
+    pub fn with_via_proxy(mut self, via_proxy: impl Into<String>) -> Self {
+        self.via_proxy = Some(via_proxy.into());
+        self
+    }
+
     /// Create a POT response from session data
-    pub fn from_session_data(session_data: crate::types::SessionData) -> Self {
+    pub fn from_session_data(session_data: &crate::types::SessionData) -> Self {
         Self {
-            po_token: session_data.po_token,
-            content_binding: session_data.content_binding,
+            po_token: session_data.po_token.clone(),
+            content_binding: session_data.content_binding.clone(),
             expires_at: session_data.expires_at,
+            token_context: None,
+            is_fallback: session_data.is_fallback.then_some(true),
+            token_type: None,
+            is_stale: None,
+            via_proxy: None,
         }
     }
 }
@@ -148,6 +269,39 @@ impl ErrorResponse {
     }
 }
 
+/// Build/version information response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionResponse {
+    /// Crate version (from Cargo.toml)
+    pub version: String,
+
+    /// Git commit SHA the binary was built from, or "unknown"
+    pub git_sha: String,
+
+    /// Build timestamp, or "unknown"
+    pub build_timestamp: String,
+
+    /// Version of the vendored `rustypipe-botguard` BotGuard integration crate
+    pub rustypipe_botguard_version: String,
+}
+
+impl VersionResponse {
+    /// Create a new version response
+    pub fn new(
+        version: impl Into<String>,
+        git_sha: impl Into<String>,
+        build_timestamp: impl Into<String>,
+        rustypipe_botguard_version: impl Into<String>,
+    ) -> Self {
+        Self {
+            version: version.into(),
+            git_sha: git_sha.into(),
+            build_timestamp: build_timestamp.into(),
+            rustypipe_botguard_version: rustypipe_botguard_version.into(),
+        }
+    }
+}
+
 /// Minter cache keys response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MinterCacheResponse {
@@ -184,6 +338,201 @@ impl MinterCacheResponse {
     }
 }
 
+/// Summary of a `/warmup` pre-minting run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupResponse {
+    /// Number of bindings that were successfully minted and cached
+    pub warmed: usize,
+
+    /// Number of bindings that failed to mint
+    pub failed: usize,
+}
+
+impl WarmupResponse {
+    /// Create a new warmup response
+    pub fn new(warmed: usize, failed: usize) -> Self {
+        Self { warmed, failed }
+    }
+}
+
+/// Result of a `/reinitialize` BotGuard reinitialization request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReinitializeResponse {
+    /// Expiry timestamp of the freshly-initialized BotGuard instance
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+
+    /// Lifetime in seconds of the freshly-initialized BotGuard instance
+    #[serde(rename = "lifetimeSecs")]
+    pub lifetime_secs: u32,
+}
+
+impl ReinitializeResponse {
+    /// Create a new reinitialize response
+    pub fn new(expires_at: DateTime<Utc>, lifetime_secs: u32) -> Self {
+        Self {
+            expires_at,
+            lifetime_secs,
+        }
+    }
+}
+
+/// Result of a deep `GET /health?deep=true` check that proves BotGuard can
+/// actually mint, rather than just that it's initialized
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeepHealthResponse {
+    /// How long the throwaway mint took, in milliseconds
+    #[serde(rename = "mintLatencyMs")]
+    pub mint_latency_ms: u64,
+}
+
+impl DeepHealthResponse {
+    /// Create a new deep health response
+    pub fn new(mint_latency_ms: u64) -> Self {
+        Self { mint_latency_ms }
+    }
+}
+
+/// Most recent `generate_pot_token` failure, for lightweight visibility into
+/// intermittent failures without trawling logs. Cleared on the next success.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsResponse {
+    /// Message from the most recent `generate_pot_token` failure, if one has
+    /// happened since the last success
+    #[serde(rename = "lastError", skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+
+    /// When `last_error` occurred
+    #[serde(rename = "lastErrorAt", skip_serializing_if = "Option::is_none")]
+    pub last_error_at: Option<DateTime<Utc>>,
+}
+
+impl DiagnosticsResponse {
+    /// Build a diagnostics response from the session manager's last-error state
+    pub fn new(last_error: Option<(String, DateTime<Utc>)>) -> Self {
+        match last_error {
+            Some((message, occurred_at)) => Self {
+                last_error: Some(message),
+                last_error_at: Some(occurred_at),
+            },
+            None => Self {
+                last_error: None,
+                last_error_at: None,
+            },
+        }
+    }
+}
+
+/// Token generation success/failure totals, exposed for operator dashboards
+/// that would rather poll a small JSON endpoint than scrape `/metrics`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheStatsResponse {
+    /// Total successful `generate_pot_token` calls since startup
+    pub success_count: u64,
+
+    /// Total failed `generate_pot_token` calls since startup
+    pub failure_count: u64,
+}
+
+impl CacheStatsResponse {
+    /// Create a new cache stats response
+    pub fn new(success_count: u64, failure_count: u64) -> Self {
+        Self {
+            success_count,
+            failure_count,
+        }
+    }
+}
+
+/// Result of a `POST /cache/prune` request, which evicts only expired
+/// entries rather than clearing everything like `/invalidate_caches` does
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachePruneResponse {
+    /// Number of expired session-data cache entries removed
+    #[serde(rename = "sessionEntriesRemoved")]
+    pub session_entries_removed: u64,
+
+    /// Number of expired minter cache entries removed
+    #[serde(rename = "minterEntriesRemoved")]
+    pub minter_entries_removed: u64,
+}
+
+impl CachePruneResponse {
+    /// Create a new cache prune response
+    pub fn new(session_entries_removed: u64, minter_entries_removed: u64) -> Self {
+        Self {
+            session_entries_removed,
+            minter_entries_removed,
+        }
+    }
+}
+
+/// A single session-data cache entry, as listed by `GET /cache/entries`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionCacheEntry {
+    /// Content binding the cached token was minted for
+    #[serde(rename = "contentBinding")]
+    pub content_binding: String,
+
+    /// When the cached token expires
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl SessionCacheEntry {
+    /// Create a new session cache entry
+    pub fn new(content_binding: impl Into<String>, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            content_binding: content_binding.into(),
+            expires_at,
+        }
+    }
+}
+
+/// A single minter cache entry, as listed by `GET /minter_cache/detail` - a
+/// more detailed sibling of the keys-only `GET /minter_cache` response that
+/// also surfaces expiry information
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinterCacheDetailEntry {
+    /// Minter cache key the entry was stored under
+    pub key: String,
+
+    /// When the cached integrity token expires
+    #[serde(rename = "expiresAt")]
+    pub expires_at: DateTime<Utc>,
+
+    /// Seconds remaining until expiry, clamped at 0
+    #[serde(rename = "remainingSeconds")]
+    pub remaining_seconds: i64,
+
+    /// TTL, in seconds, that was estimated when the entry was minted
+    #[serde(rename = "estimatedTtlSecs")]
+    pub estimated_ttl_secs: u32,
+
+    /// Whether the entry has already expired
+    #[serde(rename = "isExpired")]
+    pub is_expired: bool,
+}
+
+impl MinterCacheDetailEntry {
+    /// Create a new minter cache detail entry
+    pub fn new(
+        key: impl Into<String>,
+        expires_at: DateTime<Utc>,
+        estimated_ttl_secs: u32,
+        is_expired: bool,
+    ) -> Self {
+        let remaining_seconds = (expires_at - Utc::now()).num_seconds().max(0);
+        Self {
+            key: key.into(),
+            expires_at,
+            remaining_seconds,
+            estimated_ttl_secs,
+            is_expired,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +575,111 @@ mod tests {
         assert_eq!(deserialized.content_binding, "test_binding");
     }
 
+    #[test]
+    fn test_pot_response_with_token_context() {
+        let expires_at = Utc::now() + Duration::hours(6);
+        let response = PotResponse::new("test_token", "test_binding", expires_at)
+            .with_token_context(crate::types::TokenContext::Subs);
+
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"tokenContext\":\"subs\""));
+
+        let deserialized: PotResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.token_context,
+            Some(crate::types::TokenContext::Subs)
+        );
+    }
+
+    #[test]
+    fn test_pot_response_token_context_omitted_when_absent() {
+        let response = PotResponse::new("test_token", "test_binding", Utc::now());
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(!json.contains("tokenContext"));
+    }
+
+    #[test]
+    fn test_pot_response_with_token_type() {
+        let expires_at = Utc::now() + Duration::hours(6);
+        let response = PotResponse::new("test_token", "test_binding", expires_at)
+            .with_token_type(crate::types::internal::PotTokenType::ColdStart);
+
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"tokenType\":\"cold_start\""));
+
+        let deserialized: PotResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.token_type,
+            Some(crate::types::internal::PotTokenType::ColdStart)
+        );
+    }
+
+    #[test]
+    fn test_pot_response_token_type_omitted_when_absent() {
+        let response = PotResponse::new("test_token", "test_binding", Utc::now());
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(!json.contains("tokenType"));
+    }
+
+    #[test]
+    fn test_pot_response_with_via_proxy() {
+        let response = PotResponse::new("test_token", "test_binding", Utc::now())
+            .with_via_proxy("proxy.example.com:8080");
+
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"viaProxy\":\"proxy.example.com:8080\""));
+
+        let deserialized: PotResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.via_proxy,
+            Some("proxy.example.com:8080".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pot_response_via_proxy_omitted_when_absent() {
+        let response = PotResponse::new("test_token", "test_binding", Utc::now());
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(!json.contains("viaProxy"));
+    }
+
+    #[test]
+    fn test_pot_response_remaining_seconds_close_to_ttl() {
+        let ttl = Duration::hours(6);
+        let response = PotResponse::new("test_token", "test_binding", Utc::now() + ttl);
+
+        let json = serde_json::to_value(&response).unwrap();
+        let remaining_seconds = json["remainingSeconds"].as_i64().unwrap();
+
+        assert!(remaining_seconds > 0);
+        assert!((ttl.num_seconds() - remaining_seconds).abs() < 5);
+    }
+
+    #[test]
+    fn test_pot_response_remaining_seconds_clamped_at_zero_when_expired() {
+        let response = PotResponse::new(
+            "test_token",
+            "test_binding",
+            Utc::now() - Duration::hours(1),
+        );
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["remainingSeconds"].as_i64().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_pot_response_remaining_seconds_excluded_from_deserialization() {
+        let response = PotResponse::new(
+            "test_token",
+            "test_binding",
+            Utc::now() + Duration::hours(6),
+        );
+        let json = serde_json::to_string(&response).unwrap();
+
+        let deserialized: PotResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, response);
+    }
+
     #[test]
     fn test_ping_response() {
         let response = PingResponse::new(3600, "1.0.0");
@@ -288,6 +742,20 @@ mod tests {
         assert!(error.version.is_some());
     }
 
+    #[test]
+    fn test_version_response_serialization() {
+        let response = VersionResponse::new("0.6.4", "abc123", "2026-08-08T00:00:00Z", "0.1.2");
+
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"version\":\"0.6.4\""));
+        assert!(json.contains("\"git_sha\":\"abc123\""));
+        assert!(json.contains("\"rustypipe_botguard_version\":\"0.1.2\""));
+
+        let deserialized: VersionResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.version, "0.6.4");
+        assert_eq!(deserialized.build_timestamp, "2026-08-08T00:00:00Z");
+    }
+
     #[test]
     fn test_minter_cache_response() {
         let mut response = MinterCacheResponse::empty();
@@ -0,0 +1,112 @@
+//! JSON Lines audit logging of successful POT mints
+//!
+//! Gated by [`crate::config::settings::LoggingSettings::audit_file`]; when
+//! set, every successful mint appends one JSON line recording what was
+//! minted - never the raw token - for operators who need an audit trail.
+//! Writes happen on a dedicated worker thread (mirroring
+//! [`crate::session::botguard::BotGuardClient`]'s worker) so a slow disk
+//! never blocks the async mint path.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+/// One audit record, written as a single JSON line
+#[derive(Debug, Serialize)]
+struct AuditRecord {
+    timestamp: DateTime<Utc>,
+    /// SHA-256 hex digest of the content binding - never the plaintext
+    content_binding_hash: String,
+    proxy_host: Option<String>,
+    token_type: Option<crate::types::internal::PotTokenType>,
+    expires_at: DateTime<Utc>,
+}
+
+/// Appends a JSON Lines audit record to [`LoggingSettings::audit_file`](crate::config::settings::LoggingSettings::audit_file)
+/// for every successful mint
+#[derive(Debug, Clone)]
+pub struct AuditLogger {
+    sender: mpsc::Sender<AuditRecord>,
+}
+
+impl AuditLogger {
+    /// Open `path` for appending and spawn the worker thread that writes to it
+    pub fn new(path: PathBuf) -> std::io::Result<Self> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        let (sender, receiver) = mpsc::channel::<AuditRecord>();
+
+        std::thread::spawn(move || {
+            for record in receiver {
+                match serde_json::to_string(&record) {
+                    Ok(line) => {
+                        if let Err(e) = writeln!(file, "{line}") {
+                            tracing::warn!("Failed to write audit log record: {}", e);
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to serialize audit log record: {}", e),
+                }
+            }
+        });
+
+        Ok(Self { sender })
+    }
+
+    /// Hash `content_binding` and enqueue an audit record for a successful
+    /// mint. Never blocks on disk I/O; a disconnected channel (the
+    /// worker thread panicked) just means this mint goes unaudited rather
+    /// than failing the mint itself.
+    pub fn record_mint(
+        &self,
+        content_binding: &str,
+        proxy_host: Option<String>,
+        token_type: Option<crate::types::internal::PotTokenType>,
+        expires_at: DateTime<Utc>,
+    ) {
+        let content_binding_hash = format!("{:x}", Sha256::digest(content_binding.as_bytes()));
+        let _ = self.sender.send(AuditRecord {
+            timestamp: Utc::now(),
+            content_binding_hash,
+            proxy_host,
+            token_type,
+            expires_at,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_mint_writes_hashed_binding_not_plaintext() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let logger = AuditLogger::new(path.clone()).unwrap();
+
+        logger.record_mint(
+            "plaintext_video_id",
+            Some("proxy.example.com".to_string()),
+            None,
+            Utc::now(),
+        );
+
+        // Give the worker thread a moment to flush the write
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 1);
+        assert!(!lines[0].contains("plaintext_video_id"));
+
+        let record: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        let expected_hash = format!("{:x}", Sha256::digest(b"plaintext_video_id"));
+        assert_eq!(record["content_binding_hash"], expected_hash);
+        assert_eq!(record["proxy_host"], "proxy.example.com");
+    }
+}
@@ -107,7 +107,7 @@ impl FileCache {
         for (content_binding, entry) in cache_entries {
             match self.parse_cache_entry(&content_binding, entry) {
                 Ok(session_data) => {
-                    session_caches.insert(content_binding, session_data);
+                    session_caches.insert(content_binding, std::sync::Arc::new(session_data));
                 }
                 Err(e) => {
                     warn!("Ignored cache entry for '{}': {}", content_binding, e);
@@ -148,7 +148,7 @@ impl FileCache {
             .into_iter()
             .map(|(content_binding, session_data)| {
                 let entry = CacheEntry {
-                    po_token: session_data.po_token,
+                    po_token: session_data.po_token.clone(),
                     content_binding: session_data.content_binding.clone(),
                     expires_at: session_data.expires_at.to_rfc3339(),
                 };
@@ -192,7 +192,7 @@ mod tests {
         let expires_at = Utc::now() + Duration::hours(6);
         session_caches.insert(
             "test_video_id".to_string(),
-            SessionData::new("test_token", "test_video_id", expires_at),
+            std::sync::Arc::new(SessionData::new("test_token", "test_video_id", expires_at)),
         );
 
         // Save cache
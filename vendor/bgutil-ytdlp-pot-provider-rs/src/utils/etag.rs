@@ -0,0 +1,38 @@
+//! Weak ETag helper for conditional GET support
+//!
+//! Backs `If-None-Match` handling on read-only debug/monitoring endpoints
+//! (`/minter_cache`, `/cache/stats`) that change rarely relative to how often
+//! a monitoring tool polls them.
+
+use std::hash::{Hash, Hasher};
+
+/// Compute a weak ETag (`W/"<hex>"`) from a hashable value.
+///
+/// Weak because it's derived from [`std::collections::hash_map::DefaultHasher`],
+/// a non-cryptographic hash - good enough to detect "did this change" between
+/// two polls, not a content-integrity guarantee.
+pub fn weak_etag(value: impl Hash) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("W/\"{:x}\"", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weak_etag_is_stable_for_equal_input() {
+        assert_eq!(weak_etag(("a", 1usize)), weak_etag(("a", 1usize)));
+    }
+
+    #[test]
+    fn test_weak_etag_changes_with_input() {
+        assert_ne!(weak_etag(("a", 1usize)), weak_etag(("a", 2usize)));
+    }
+
+    #[test]
+    fn test_weak_etag_has_weak_prefix() {
+        assert!(weak_etag("anything").starts_with("W/\""));
+    }
+}
@@ -0,0 +1,284 @@
+//! In-memory metrics
+//!
+//! Hand-rolled rather than pulling in a `prometheus`/`metrics` crate dependency,
+//! since the server only needs a histogram (BotGuard mint latency) and a
+//! handful of counters, all exposed at `GET /metrics` in Prometheus text
+//! exposition format.
+
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Upper bounds (seconds) for each `botguard_mint_seconds` bucket, spanning
+/// sub-second to multi-second mint times
+const BOTGUARD_MINT_BUCKETS_SECS: [f64; 8] = [0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Histogram tracking how long BotGuard takes to mint a POT token
+#[derive(Debug, Default)]
+pub struct BotguardMintHistogram {
+    /// Cumulative count of observations with duration `<= BOTGUARD_MINT_BUCKETS_SECS[i]`
+    bucket_counts: [AtomicU64; BOTGUARD_MINT_BUCKETS_SECS.len()],
+    /// Sum of all observed durations, in milliseconds
+    sum_millis: AtomicU64,
+    /// Total number of observations recorded
+    total: AtomicU64,
+}
+
+impl BotguardMintHistogram {
+    /// Create an empty histogram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one observed BotGuard mint duration
+    pub fn observe(&self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        for (bucket, upper_bound) in self
+            .bucket_counts
+            .iter()
+            .zip(BOTGUARD_MINT_BUCKETS_SECS.iter())
+        {
+            if secs <= *upper_bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.sum_millis
+            .fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+        self.total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Total number of observations recorded so far
+    pub fn count(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    /// Render as a `botguard_mint_seconds` Prometheus text exposition metric
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP botguard_mint_seconds Time spent minting a POT token via BotGuard\n");
+        out.push_str("# TYPE botguard_mint_seconds histogram\n");
+
+        for (bucket, upper_bound) in self
+            .bucket_counts
+            .iter()
+            .zip(BOTGUARD_MINT_BUCKETS_SECS.iter())
+        {
+            out.push_str(&format!(
+                "botguard_mint_seconds_bucket{{le=\"{upper_bound}\"}} {}\n",
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+
+        let total = self.count();
+        out.push_str(&format!(
+            "botguard_mint_seconds_bucket{{le=\"+Inf\"}} {total}\n"
+        ));
+        out.push_str(&format!(
+            "botguard_mint_seconds_sum {}\n",
+            self.sum_millis.load(Ordering::Relaxed) as f64 / 1000.0
+        ));
+        out.push_str(&format!("botguard_mint_seconds_count {total}\n"));
+
+        out
+    }
+}
+
+/// Counters tracking `generate_pot_token` outcomes
+///
+/// Failures are keyed by [`crate::Error::category`] so operators can alert
+/// on rising failure rates and tell which subsystem is responsible, without
+/// needing a separate counter per error variant.
+#[derive(Debug, Default)]
+pub struct TokenGenerationCounters {
+    /// Total number of successful `generate_pot_token` calls
+    success: AtomicU64,
+    /// Total failed calls, keyed by error category
+    failures: DashMap<&'static str, AtomicU64>,
+}
+
+impl TokenGenerationCounters {
+    /// Create counters starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a successful token generation
+    pub fn record_success(&self) {
+        self.success.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a failed token generation, keyed by error category
+    pub fn record_failure(&self, category: &'static str) {
+        self.failures
+            .entry(category)
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Total number of successful generations recorded so far
+    pub fn success_count(&self) -> u64 {
+        self.success.load(Ordering::Relaxed)
+    }
+
+    /// Total number of failed generations recorded so far, across all categories
+    pub fn failure_count(&self) -> u64 {
+        self.failures
+            .iter()
+            .map(|entry| entry.value().load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Render as `pot_token_generations_total` Prometheus text exposition counters
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str(
+            "# HELP pot_token_generations_total Total POT token generations by outcome\n",
+        );
+        out.push_str("# TYPE pot_token_generations_total counter\n");
+        out.push_str(&format!(
+            "pot_token_generations_total{{outcome=\"success\"}} {}\n",
+            self.success_count()
+        ));
+        for entry in self.failures.iter() {
+            out.push_str(&format!(
+                "pot_token_generations_total{{outcome=\"failure\",category=\"{}\"}} {}\n",
+                entry.key(),
+                entry.value().load(Ordering::Relaxed)
+            ));
+        }
+
+        out
+    }
+}
+
+/// Counters tracking cache entries evicted from [`crate::session::SessionManager`]'s
+/// session data and minter caches
+///
+/// Keyed by cache name and eviction reason so operators can tell a TTL
+/// expiry sweep apart from an LRU eviction triggered by hitting a size
+/// limit, and size `token.max_minter_entries`/cache TTLs accordingly.
+#[derive(Debug, Default)]
+pub struct CacheEvictionCounters {
+    /// Total evictions, keyed by `(cache, reason)`
+    counts: DashMap<(&'static str, &'static str), AtomicU64>,
+}
+
+impl CacheEvictionCounters {
+    /// Create counters starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `count` entries evicted from `cache` for `reason`
+    pub fn record(&self, cache: &'static str, reason: &'static str, count: u64) {
+        if count == 0 {
+            return;
+        }
+        self.counts
+            .entry((cache, reason))
+            .or_default()
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Total evictions recorded so far, across every cache and reason
+    pub fn total(&self) -> u64 {
+        self.counts
+            .iter()
+            .map(|entry| entry.value().load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Render as `cache_evictions_total` Prometheus text exposition counters
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str(
+            "# HELP cache_evictions_total Total entries evicted from session/minter caches\n",
+        );
+        out.push_str("# TYPE cache_evictions_total counter\n");
+        for entry in self.counts.iter() {
+            let (cache, reason) = entry.key();
+            out.push_str(&format!(
+                "cache_evictions_total{{cache=\"{cache}\",reason=\"{reason}\"}} {}\n",
+                entry.value().load(Ordering::Relaxed)
+            ));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_histogram_has_zero_count() {
+        let histogram = BotguardMintHistogram::new();
+        assert_eq!(histogram.count(), 0);
+    }
+
+    #[test]
+    fn test_observe_increments_count() {
+        let histogram = BotguardMintHistogram::new();
+        histogram.observe(Duration::from_millis(50));
+        histogram.observe(Duration::from_secs(2));
+
+        assert_eq!(histogram.count(), 2);
+    }
+
+    #[test]
+    fn test_render_includes_observed_bucket() {
+        let histogram = BotguardMintHistogram::new();
+        histogram.observe(Duration::from_millis(50));
+
+        let rendered = histogram.render();
+        assert!(rendered.contains("botguard_mint_seconds_bucket{le=\"0.1\"} 1"));
+        assert!(rendered.contains("botguard_mint_seconds_count 1"));
+    }
+
+    #[test]
+    fn test_token_generation_counters_track_success_and_failure() {
+        let counters = TokenGenerationCounters::new();
+        counters.record_success();
+        counters.record_success();
+        counters.record_failure("botguard");
+        counters.record_failure("botguard");
+        counters.record_failure("validation");
+
+        assert_eq!(counters.success_count(), 2);
+        assert_eq!(counters.failure_count(), 3);
+
+        let rendered = counters.render();
+        assert!(rendered.contains("pot_token_generations_total{outcome=\"success\"} 2"));
+        assert!(rendered.contains(
+            "pot_token_generations_total{outcome=\"failure\",category=\"botguard\"} 2"
+        ));
+        assert!(rendered.contains(
+            "pot_token_generations_total{outcome=\"failure\",category=\"validation\"} 1"
+        ));
+    }
+
+    #[test]
+    fn test_cache_eviction_counters_track_by_cache_and_reason() {
+        let counters = CacheEvictionCounters::new();
+        counters.record("session", "expiry", 3);
+        counters.record("minter", "size", 1);
+        counters.record("minter", "size", 1);
+
+        assert_eq!(counters.total(), 5);
+
+        let rendered = counters.render();
+        assert!(rendered.contains("cache_evictions_total{cache=\"session\",reason=\"expiry\"} 3"));
+        assert!(rendered.contains("cache_evictions_total{cache=\"minter\",reason=\"size\"} 2"));
+    }
+
+    #[test]
+    fn test_cache_eviction_counters_ignore_zero_count() {
+        let counters = CacheEvictionCounters::new();
+        counters.record("session", "expiry", 0);
+
+        assert_eq!(counters.total(), 0);
+        assert!(counters.render().contains("# TYPE cache_evictions_total"));
+        assert!(!counters.render().contains("cache=\"session\""));
+    }
+}
@@ -2,7 +2,11 @@
 //!
 //! This module contains utility functions used throughout the application.
 
+pub mod audit;
 pub mod cache;
+pub mod etag;
+pub mod metrics;
+pub mod signature;
 pub mod version;
 
 pub use version::{VERSION, get_version};
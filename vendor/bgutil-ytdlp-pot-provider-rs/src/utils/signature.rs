@@ -0,0 +1,78 @@
+//! HMAC-SHA256 signing for response bodies
+//!
+//! Backs the optional `X-POT-Signature` header gated by
+//! [`crate::config::ServerSettings::response_signing_key`], letting a
+//! client verify a `/get_pot` response wasn't tampered with by a cache or
+//! other intermediary sitting between it and the server.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Sign `body` with `key`, returning the base64-encoded HMAC-SHA256 value
+/// to send as `X-POT-Signature`
+pub fn sign_response_body(key: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(key.as_bytes()).expect("HMAC accepts a key of any length");
+    mac.update(body);
+    BASE64.encode(mac.finalize().into_bytes())
+}
+
+/// Verify that `signature` (as produced by [`sign_response_body`]) matches
+/// `body` under `key`. Returns `false` for a malformed (non-base64)
+/// signature rather than erroring, since that's indistinguishable from
+/// tampering as far as the caller is concerned.
+pub fn verify_response_signature(key: &str, body: &[u8], signature: &str) -> bool {
+    let Ok(mut mac) = HmacSha256::new_from_slice(key.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+
+    let Ok(decoded) = BASE64.decode(signature) else {
+        return false;
+    };
+    mac.verify_slice(&decoded).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_response_signature_accepts_matching_signature() {
+        let body = br#"{"po_token":"abc","content_binding":"video"}"#;
+        let signature = sign_response_body("test-key", body);
+
+        assert!(verify_response_signature("test-key", body, &signature));
+    }
+
+    #[test]
+    fn test_verify_response_signature_rejects_altered_body() {
+        let body = br#"{"po_token":"abc","content_binding":"video"}"#;
+        let signature = sign_response_body("test-key", body);
+
+        let altered_body = br#"{"po_token":"xyz","content_binding":"video"}"#;
+        assert!(!verify_response_signature(
+            "test-key",
+            altered_body,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_verify_response_signature_rejects_wrong_key() {
+        let body = br#"{"po_token":"abc","content_binding":"video"}"#;
+        let signature = sign_response_body("test-key", body);
+
+        assert!(!verify_response_signature("wrong-key", body, &signature));
+    }
+
+    #[test]
+    fn test_verify_response_signature_rejects_malformed_signature() {
+        let body = b"some body";
+        assert!(!verify_response_signature("test-key", body, "not-base64!!"));
+    }
+}
@@ -13,12 +13,38 @@ pub fn get_version() -> &'static str {
 /// Get detailed version information including git commit
 pub fn get_detailed_version() -> String {
     let version = get_version();
-    let git_hash = option_env!("GIT_HASH").unwrap_or("unknown");
-    let build_date = option_env!("BUILD_DATE").unwrap_or("unknown");
+    let git_hash = get_git_sha();
+    let build_date = get_build_timestamp();
 
     format!("{} ({}@{})", version, git_hash, build_date)
 }
 
+/// Get the git commit SHA embedded at build time, or "unknown" if unavailable
+///
+/// Populated by `build.rs` from `git rev-parse HEAD`; falls back to
+/// "unknown" when building outside a git checkout (e.g. from a source
+/// tarball or in an environment without `git` on `PATH`).
+pub fn get_git_sha() -> &'static str {
+    option_env!("GIT_SHA").unwrap_or("unknown")
+}
+
+/// Get the build timestamp embedded at build time, or "unknown" if unavailable
+pub fn get_build_timestamp() -> &'static str {
+    option_env!("BUILD_TIMESTAMP").unwrap_or("unknown")
+}
+
+/// Version of the vendored `rustypipe-botguard` BotGuard integration crate
+///
+/// Kept in sync with the pinned dependency version in Cargo.toml rather than
+/// read from Cargo metadata, since transitive dependency versions aren't
+/// available to the crate being built without a build script.
+pub const RUSTYPIPE_BOTGUARD_VERSION: &str = "0.1.2";
+
+/// Get the version of the vendored `rustypipe-botguard` crate
+pub fn get_rustypipe_botguard_version() -> &'static str {
+    RUSTYPIPE_BOTGUARD_VERSION
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,4 +62,19 @@ mod tests {
         assert!(!detailed.is_empty());
         assert!(detailed.contains(env!("CARGO_PKG_VERSION")));
     }
+
+    #[test]
+    fn test_get_git_sha_is_non_empty() {
+        assert!(!get_git_sha().is_empty());
+    }
+
+    #[test]
+    fn test_get_build_timestamp_is_non_empty() {
+        assert!(!get_build_timestamp().is_empty());
+    }
+
+    #[test]
+    fn test_get_rustypipe_botguard_version_is_non_empty() {
+        assert!(!get_rustypipe_botguard_version().is_empty());
+    }
 }
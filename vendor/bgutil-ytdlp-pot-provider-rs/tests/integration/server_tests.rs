@@ -15,7 +15,7 @@ use tower::ServiceExt;
 /// Create test application for integration tests
 fn create_test_app() -> axum::Router {
     let settings = Settings::default();
-    create_app(settings)
+    create_app(settings).unwrap()
 }
 
 #[tokio::test]
@@ -10,7 +10,7 @@ use tower::ServiceExt;
 /// Create test application for integration tests
 fn create_test_app() -> axum::Router {
     let settings = Settings::default();
-    create_app(settings)
+    create_app(settings).unwrap()
 }
 
 #[tokio::test]
@@ -130,3 +130,25 @@ async fn test_server_cors_headers() {
     let headers = response.headers();
     assert!(headers.contains_key("access-control-allow-origin"));
 }
+
+#[tokio::test]
+async fn test_server_openapi_json_endpoint() {
+    let app = create_test_app();
+
+    let request = axum::http::Request::builder()
+        .uri("/openapi.json")
+        .method("GET")
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let spec: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(spec["paths"]["/get_pot"].is_object());
+}